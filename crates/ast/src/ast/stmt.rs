@@ -109,6 +109,10 @@ pub struct StmtTry<'ast> {
 /// Reference: <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.catchClause>
 #[derive(Debug)]
 pub struct TryCatchClause<'ast> {
+    /// The span of the clause header, excluding the block: `returns (...)` or `catch Name(...)`.
+    ///
+    /// This is empty if the successful case has no `returns`.
+    pub span: Span,
     pub name: Option<Ident>,
     pub args: ParameterList<'ast>,
     pub block: Block<'ast>,
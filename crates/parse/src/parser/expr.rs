@@ -47,10 +47,10 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         while precedence >= min_precedence {
             while token_precedence(&self.token) == precedence {
                 // Parse a**b**c as a**(b**c)
-                let next_precedence = if self.token.kind == TokenKind::BinOp(BinOpToken::Star) {
-                    precedence + 1
-                } else {
+                let next_precedence = if self.token.kind == TokenKind::StarStar {
                     precedence
+                } else {
+                    precedence + 1
                 };
 
                 let token = self.token.clone();
@@ -297,7 +297,7 @@ fn token_precedence(t: &Token) -> usize {
         BinOp(Star) => 13,
         BinOp(Slash) => 13,
         BinOp(Percent) => 13,
-        StarStar => 4,
+        StarStar => 14,
         EqEq => 6,
         Ne => 6,
         Lt => 7,
@@ -134,24 +134,28 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         let expr = self.parse_expr()?;
 
         let mut clauses = SmallVec::<[_; 4]>::new();
-        let returns = if self.eat_keyword(kw::Returns) {
-            self.parse_parameter_list(false, VarFlags::FUNCTION)?
+        let lo = self.token.span;
+        let (span, returns) = if self.eat_keyword(kw::Returns) {
+            let returns = self.parse_parameter_list(false, VarFlags::FUNCTION)?;
+            (lo.to(self.prev_token.span), returns)
         } else {
-            Default::default()
+            (lo.shrink_to_lo(), Default::default())
         };
         let block = self.parse_block()?;
-        clauses.push(TryCatchClause { name: None, args: returns, block });
+        clauses.push(TryCatchClause { span, name: None, args: returns, block });
 
         self.expect_keyword(kw::Catch)?;
         loop {
+            let lo = self.prev_token.span;
             let name = self.parse_ident_opt()?;
             let args = if self.check(&TokenKind::OpenDelim(Delimiter::Parenthesis)) {
                 self.parse_parameter_list(false, VarFlags::FUNCTION)?
             } else {
                 Default::default()
             };
+            let span = lo.to(self.prev_token.span);
             let block = self.parse_block()?;
-            clauses.push(TryCatchClause { name, args, block });
+            clauses.push(TryCatchClause { span, name, args, block });
             if !self.eat_keyword(kw::Catch) {
                 break;
             }
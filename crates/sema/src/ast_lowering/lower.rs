@@ -16,15 +16,17 @@ impl<'ast> super::LoweringContext<'_, 'ast, '_> {
                 file: source.file.clone(),
                 imports: self.arena.alloc_slice_copy(&source.imports),
                 items: &[],
+                using_directives: &[],
             };
             if let Some(ast) = &source.ast {
                 let mut items = SmallVec::<[_; 16]>::new();
                 self.current_source_id = id;
                 for item in ast.items.iter() {
                     match &item.kind {
-                        ast::ItemKind::Pragma(_)
-                        | ast::ItemKind::Import(_)
-                        | ast::ItemKind::Using(_) => {}
+                        ast::ItemKind::Pragma(_) | ast::ItemKind::Import(_) => {}
+                        ast::ItemKind::Using(using) => {
+                            self.using_directives.push((id, None, item.span, using));
+                        }
                         ast::ItemKind::Contract(_)
                         | ast::ItemKind::Function(_)
                         | ast::ItemKind::Variable(_)
@@ -55,12 +57,14 @@ impl<'ast> super::LoweringContext<'_, 'ast, '_> {
 
             // Set later.
             bases: &[],
+            base_args: &[],
             linearized_bases: &[],
 
             ctor: None,
             fallback: None,
             receive: None,
             items: &[],
+            using_directives: &[],
         });
         let prev_contract_id = Option::replace(&mut self.current_contract_id, id);
         debug_assert_eq!(prev_contract_id, None);
@@ -71,7 +75,11 @@ impl<'ast> super::LoweringContext<'_, 'ast, '_> {
                 ast::ItemKind::Pragma(_)
                 | ast::ItemKind::Import(_)
                 | ast::ItemKind::Contract(_) => unreachable!("illegal item in contract body"),
-                ast::ItemKind::Using(_) => continue,
+                ast::ItemKind::Using(using) => {
+                    let source = self.current_source_id;
+                    self.using_directives.push((source, Some(id), item.span, using));
+                    continue;
+                }
                 ast::ItemKind::Variable(_) => {
                     let hir::ItemId::Variable(id) = self.lower_item(item) else { unreachable!() };
                     items.push(hir::ItemId::Variable(id));
@@ -155,8 +163,14 @@ impl<'ast> super::LoweringContext<'_, 'ast, '_> {
             overrides: &[],
             visibility: visibility.unwrap_or_else(|| {
                 let is_free = self.current_contract_id.is_none();
+                let is_interface = self
+                    .current_contract_id
+                    .is_some_and(|id| self.hir.contract(id).kind.is_interface());
                 if kind.is_modifier() || is_free {
                     ast::Visibility::Internal
+                } else if is_interface {
+                    // Missing visibility is reported in AST validation.
+                    ast::Visibility::External
                 } else {
                     ast::Visibility::Public
                 }
@@ -8,7 +8,7 @@ use solar_data_structures::{
     map::FxHashMap,
     trustme,
 };
-use solar_interface::{diagnostics::DiagCtxt, Session};
+use solar_interface::{diagnostics::DiagCtxt, Session, Span};
 
 mod lower;
 
@@ -55,6 +55,9 @@ struct LoweringContext<'sess, 'ast, 'hir> {
     hir: Hir<'hir>,
     /// Mapping from Hir ItemId to AST Item. Does not include function parameters or bodies.
     hir_to_ast: FxHashMap<hir::ItemId, &'ast ast::Item<'ast>>,
+    /// The `using for` directives to lower, with the scope they are declared in and their span.
+    using_directives:
+        Vec<(hir::SourceId, Option<hir::ContractId>, Span, &'ast ast::UsingDirective<'ast>)>,
 
     /// Current source being lowered.
     current_source_id: hir::SourceId,
@@ -73,6 +76,7 @@ impl<'sess, 'hir> LoweringContext<'sess, '_, 'hir> {
             current_source_id: hir::SourceId::MAX,
             current_contract_id: None,
             hir_to_ast: FxHashMap::default(),
+            using_directives: Vec::new(),
             resolver: SymbolResolver::new(&sess.dcx),
         }
     }
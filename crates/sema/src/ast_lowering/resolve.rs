@@ -294,6 +294,12 @@ impl<'hir> super::LoweringContext<'_, '_, 'hir> {
                 cx.lower_variables(ast_event.parameters, hir::VarKind::Event);
         }
 
+        self.resolve_using_directives(next_id);
+
+        for id in self.hir.contract_ids() {
+            self.resolve_base_args(id, next_id);
+        }
+
         // Resolve constants and state variables.
         let normal_vars = self.hir.variables.len();
         for id in self.hir.variable_ids() {
@@ -315,7 +321,7 @@ impl<'hir> super::LoweringContext<'_, '_, 'hir> {
 
             let scopes = SymbolResolverScopes::new_in(func.source, func.contract);
 
-            self.hir.functions[id].modifiers = {
+            let modifiers = {
                 let mut modifiers = SmallVec::<[_; 8]>::new();
                 for modifier in ast_func.header.modifiers.iter() {
                     let expected = if func.kind.is_constructor() {
@@ -343,40 +349,21 @@ impl<'hir> super::LoweringContext<'_, '_, 'hir> {
                             continue;
                         }
                     }
-                    modifiers.push(id);
+                    modifiers.push((id, modifier));
                 }
-                self.arena.alloc_smallvec(modifiers)
+                modifiers
             };
 
-            let func = self.hir.function(id);
-            self.hir.functions[id].overrides = {
-                let mut overrides = SmallVec::<[_; 8]>::new();
-                if let Some(ov) = &ast_func.header.override_ {
-                    for path in ov.paths.iter() {
-                        let Ok(id) = self.resolver.resolve_path_as(path, &scopes, "contract")
-                        else {
-                            continue;
-                        };
-                        // TODO: Move to override checker.
-                        let Some(c) = func.contract else {
-                            self.dcx().err("free functions cannot override").span(ov.span).emit();
-                            continue;
-                        };
-                        if !self.hir.contract(c).linearized_bases[1..].contains(&id) {
-                            self.dcx().err("override is not a base contract").span(ov.span).emit();
-                            continue;
-                        }
-                        overrides.push(id);
-                    }
-                }
-                self.arena.alloc_smallvec(overrides)
-            };
+            let contract = self.hir.function(id).contract;
+            self.hir.functions[id].overrides =
+                self.resolve_overrides(ast_func.header.override_.as_ref(), &scopes, contract);
 
             let mut cx = ResolveContext::new(self, scopes, next_id, Some(id));
             cx.hir.functions[id].parameters =
                 cx.lower_variables(ast_func.header.parameters, hir::VarKind::FunctionParam);
             cx.hir.functions[id].returns =
                 cx.lower_variables(ast_func.header.returns, hir::VarKind::FunctionReturn);
+            cx.hir.functions[id].modifiers = cx.lower_modifiers(&modifiers);
             if let Some(body) = &ast_func.body {
                 cx.hir.functions[id].body = Some(cx.lower_stmts(body));
             }
@@ -388,6 +375,81 @@ impl<'hir> super::LoweringContext<'_, '_, 'hir> {
         }
     }
 
+    /// Resolves the base constructor arguments given in the inheritance list of a contract.
+    fn resolve_base_args(&mut self, id: hir::ContractId, next_id: &AtomicUsize) {
+        let item = self.hir_to_ast[&hir::ItemId::Contract(id)];
+        let ast::ItemKind::Contract(ast_contract) = &item.kind else { unreachable!() };
+        let contract = self.hir.contract(id);
+        let source = contract.source;
+
+        // Base contracts are resolved in `resolve_base_contracts`, which reports errors.
+        let mut base_scopes = SymbolResolverScopes::new();
+        base_scopes.source = Some(source);
+        let mut bases = SmallVec::<[_; 8]>::new();
+        for base in ast_contract.bases.iter().filter(|base| !base.arguments.is_empty()) {
+            let Ok(decl) = self.resolver.resolve_path(base.name, &base_scopes) else { continue };
+            let Res::Item(hir::ItemId::Contract(base_id)) = decl.res else { continue };
+            if contract.bases.contains(&base_id) {
+                bases.push((hir::ItemId::Contract(base_id), base));
+            }
+        }
+        if bases.is_empty() {
+            return;
+        }
+
+        let scopes = SymbolResolverScopes::new_in(source, Some(id));
+        let mut cx = ResolveContext::new(self, scopes, next_id, None);
+        self.hir.contracts[id].base_args = cx.lower_modifiers(&bases);
+    }
+
+    /// Lowers the `using for` directives into the source or contract they are declared in.
+    fn resolve_using_directives(&mut self, next_id: &AtomicUsize) {
+        let mut directives = std::mem::take(&mut self.using_directives);
+        directives.sort_by_key(|&(source, contract, ..)| (source, contract));
+        for chunk in directives.chunk_by(|a, b| (a.0, a.1) == (b.0, b.1)) {
+            let (source, contract, ..) = chunk[0];
+            let scopes = SymbolResolverScopes::new_in(source, contract);
+            let mut cx = ResolveContext::new(self, scopes, next_id, None);
+            let arena = cx.arena;
+            let lowered = arena.alloc_slice_fill_iter(
+                chunk.iter().map(|&(_, _, span, using)| cx.lower_using_directive(span, using)),
+            );
+            match contract {
+                Some(id) => self.hir.contracts[id].using_directives = lowered,
+                None => self.hir.sources[source].using_directives = lowered,
+            }
+        }
+    }
+
+    /// Resolves the contracts listed in an `override(...)` specifier.
+    ///
+    /// Only base contracts of `contract` are returned; these are further validated by the override
+    /// checker.
+    fn resolve_overrides(
+        &mut self,
+        ov: Option<&ast::Override<'_>>,
+        scopes: &SymbolResolverScopes,
+        contract: Option<hir::ContractId>,
+    ) -> &'hir [hir::ContractId] {
+        let Some(ov) = ov else { return &[] };
+        let Some(c) = contract else {
+            self.dcx().err("free functions cannot override").span(ov.span).emit();
+            return &[];
+        };
+        let mut overrides = SmallVec::<[_; 8]>::new();
+        for path in ov.paths.iter() {
+            let Ok(id) = self.resolver.resolve_path_as(path, scopes, "contract") else {
+                continue;
+            };
+            if !self.hir.contract(c).linearized_bases[1..].contains(&id) {
+                self.dcx().err("override is not a base contract").span(path.span()).emit();
+                continue;
+            }
+            overrides.push(id);
+        }
+        self.arena.alloc_smallvec(overrides)
+    }
+
     fn resolve_var(&mut self, id: hir::VariableId, next_id: &AtomicUsize) {
         let var = self.hir.variable(id);
 
@@ -398,6 +460,8 @@ impl<'hir> super::LoweringContext<'_, '_, 'hir> {
         let ast::ItemKind::Variable(ast_var) = &ast_item.kind else { unreachable!() };
 
         let scopes = SymbolResolverScopes::new_in(var.source, var.contract);
+        self.hir.variables[id].overrides =
+            self.resolve_overrides(ast_var.override_.as_ref(), &scopes, var.contract);
         let mut cx = ResolveContext::new(self, scopes, next_id, None);
         let init = ast_var.initializer.as_deref().map(|init| cx.lower_expr(init));
         let ty = cx.lower_type(&ast_var.ty);
@@ -445,6 +509,7 @@ impl<'hir> super::LoweringContext<'_, '_, 'hir> {
         let ast_item = self.hir_to_ast[&hir::ItemId::Variable(gettee)];
         let ast::ItemKind::Variable(ast_var) = &ast_item.kind else { unreachable!() };
         let span = ast_var.span;
+        self.hir.functions[id].overrides = self.hir.variable(gettee).overrides;
 
         // https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/ast/Types.cpp#L2852
         let mut ret_ty = &self.hir.variable(gettee).ty;
@@ -665,6 +730,12 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
         }
     }
 
+    /// Returns the diagnostic context.
+    #[inline]
+    fn dcx(&self) -> &'sess DiagCtxt {
+        &self.sess.dcx
+    }
+
     fn in_scope<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
         self.scopes.enter();
         let t = f(self);
@@ -700,6 +771,70 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
         self.resolver.resolve_path_as(path, &self.scopes, description)
     }
 
+    fn lower_modifiers(
+        &mut self,
+        modifiers: &[(hir::ItemId, &ast::Modifier<'_>)],
+    ) -> &'hir [hir::Modifier<'hir>] {
+        let modifiers = modifiers
+            .iter()
+            .map(|&(id, modifier)| hir::Modifier {
+                span: modifier.span(),
+                id,
+                args: self.lower_call_args(&modifier.arguments),
+            })
+            .collect::<SmallVec<[_; 8]>>();
+        self.arena.alloc_smallvec(modifiers)
+    }
+
+    fn lower_using_directive(
+        &mut self,
+        span: Span,
+        using: &ast::UsingDirective<'_>,
+    ) -> hir::UsingDirective<'hir> {
+        let ast::UsingDirective { ref list, ref ty, global } = *using;
+        let list = match list {
+            ast::UsingList::Single(path) => {
+                match self.resolve_path_as::<hir::ContractId>(path, "library") {
+                    Ok(id) if self.hir.contract(id).kind.is_library() => {
+                        hir::UsingList::Library(id)
+                    }
+                    Ok(id) => {
+                        let found = self.hir.contract(id).description();
+                        self.resolver.report_expected("library", found, path.span());
+                        hir::UsingList::Functions(&[])
+                    }
+                    Err(_) => hir::UsingList::Functions(&[]),
+                }
+            }
+            ast::UsingList::Multiple(paths) => {
+                let mut functions = SmallVec::<[_; 8]>::new();
+                for &(ref path, operator) in paths.iter() {
+                    let Ok(decls) = self
+                        .resolver
+                        .resolve_paths(path, &self.scopes)
+                        .map_err(self.resolver.emit_resolver_error())
+                    else {
+                        continue;
+                    };
+                    for decl in decls {
+                        match decl.res {
+                            Res::Item(hir::ItemId::Function(id)) => functions
+                                .push(hir::UsingFunction { span: path.span(), id, operator }),
+                            Res::Err(_) => {}
+                            res => {
+                                let found = res.description();
+                                self.resolver.report_expected("function", found, path.span());
+                            }
+                        }
+                    }
+                }
+                hir::UsingList::Functions(self.arena.alloc_smallvec(functions))
+            }
+        };
+        let ty = ty.as_ref().map(|ty| self.lower_type(ty));
+        hir::UsingDirective { span, list, ty, global }
+    }
+
     /// Lowers the given statements by first entering a new scope.
     fn lower_block(&mut self, block: &[ast::Stmt<'_>]) -> hir::Block<'hir> {
         self.in_scope_if(!block.is_empty(), |this| this.lower_stmts(block))
@@ -777,9 +912,10 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
 
     fn lower_try_catch_clause(
         &mut self,
-        &ast::TryCatchClause { name, ref args, ref block }: &ast::TryCatchClause<'_>,
+        &ast::TryCatchClause { span, name, ref args, ref block }: &ast::TryCatchClause<'_>,
     ) -> hir::TryCatchClause<'hir> {
         self.in_scope(|this| hir::TryCatchClause {
+            span,
             name,
             args: this.lower_variables(args, hir::VarKind::TryCatch),
             block: this.lower_block(block),
@@ -835,12 +971,52 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
         self.hir.variables[id].initializer = self.lower_expr_opt(var.initializer.as_deref());
         let mut guar = Ok(());
         if let Some(name) = var.name {
+            if matches!(
+                kind,
+                hir::VarKind::FunctionParam
+                    | hir::VarKind::FunctionReturn
+                    | hir::VarKind::Statement
+                    | hir::VarKind::TryCatch
+            ) {
+                self.check_shadowing(name);
+            }
             let res = Res::Item(hir::ItemId::Variable(id));
             guar = self.scopes.current_scope().declare_res(self.sess, self.hir, name, res);
         }
         (id, guar)
     }
 
+    /// Warns if declaring `name` in the current scope shadows a declaration in an outer scope or
+    /// a builtin.
+    fn check_shadowing(&mut self, name: Ident) {
+        // Conflicts in the same scope are reported when declaring.
+        if self.scopes.current_scope().resolve(name).is_some() {
+            return;
+        }
+        let Some(&[decl, ..]) =
+            self.scopes.get(self.resolver).skip(1).find_map(|scope| scope.resolve(name))
+        else {
+            return;
+        };
+        match decl.res {
+            Res::Builtin(_) => {
+                self.dcx()
+                    .warn("this declaration shadows a builtin symbol")
+                    .span(name.span)
+                    .note(format!("the shadowed builtin is `{name}`"))
+                    .emit();
+            }
+            Res::Err(_) => {}
+            _ => {
+                self.dcx()
+                    .warn("this declaration shadows an existing declaration")
+                    .span(name.span)
+                    .span_note(decl.span, "the shadowed declaration is here")
+                    .emit();
+            }
+        }
+    }
+
     /// Desugars a `while`, `do while`, or `for` loop into a `loop` HIR statement.
     fn lower_loop_stmt(&mut self, stmt: &ast::Stmt<'_>) -> hir::StmtKind<'hir> {
         let span = stmt.span;
@@ -891,6 +1067,7 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
                     let mut body =
                         this.in_scope_if(next.is_some(), |this| this.lower_stmt_full(body));
                     let next = this.lower_expr_opt(next.as_deref());
+                    let source = hir::LoopSource::For { has_next: next.is_some() };
 
                     // <body> = { <body>; <next>; }
                     if let Some(next) = next {
@@ -911,8 +1088,7 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
                         };
                     }
 
-                    let mut kind =
-                        hir::StmtKind::Loop(self.arena.alloc_as_slice(body), hir::LoopSource::For);
+                    let mut kind = hir::StmtKind::Loop(self.arena.alloc_as_slice(body), source);
 
                     if let Some(init) = init {
                         let s = hir::Stmt { span, kind };
@@ -25,14 +25,15 @@ pub(crate) fn members_of<'gcx>(gcx: Gcx<'gcx>, ty: Ty<'gcx>) -> MemberList<'gcx>
             ElementaryType::FixedBytes(_size) => fixed_bytes(gcx),
         },
         TyKind::StringLiteral(_utf8, _size) => Default::default(),
-        TyKind::IntLiteral(_size) => Default::default(),
-        TyKind::Ref(inner, loc) => reference(gcx, ty, inner, loc),
+        TyKind::IntLiteral(..) | TyKind::RationalLiteral(_) => Default::default(),
+        TyKind::Ref(inner, loc) => reference(gcx, inner, loc),
         TyKind::DynArray(_ty) => expected_ref(),
         TyKind::Array(_ty, _len) => expected_ref(),
         TyKind::Tuple(_tys) => Default::default(),
         TyKind::Mapping(..) => Default::default(),
         TyKind::FnPtr(f) => function(gcx, f),
         TyKind::Contract(id) => contract(gcx, id),
+        TyKind::Super(id) => super_contract(gcx, id),
         TyKind::Struct(_id) => expected_ref(),
         TyKind::Enum(_id) => Default::default(),
         TyKind::Udvt(_ty, _id) => Default::default(),
@@ -42,7 +43,9 @@ pub(crate) fn members_of<'gcx>(gcx: Gcx<'gcx>, ty: Ty<'gcx>) -> MemberList<'gcx>
             .declarations
             .iter()
             .flat_map(|(&name, decls)| {
-                decls.iter().map(move |decl| Member::new(name, gcx.type_of_res(decl.res)))
+                decls
+                    .iter()
+                    .map(move |decl| Member::with_res(name, gcx.type_of_res(decl.res), decl.res))
             })
             .collect(),
         TyKind::BuiltinModule(builtin) => builtin
@@ -51,8 +54,8 @@ pub(crate) fn members_of<'gcx>(gcx: Gcx<'gcx>, ty: Ty<'gcx>) -> MemberList<'gcx>
             .iter()
             .map(|&b| Member::of_builtin(gcx, b))
             .collect(),
-        TyKind::Type(_ty) => type_type(gcx, ty),
-        TyKind::Meta(_ty) => meta(gcx, ty),
+        TyKind::Type(ty) => type_type(gcx, ty),
+        TyKind::Meta(ty) => meta(gcx, ty),
         TyKind::Err(_guar) => Default::default(),
     })
 }
@@ -62,15 +65,22 @@ pub struct Member<'gcx> {
     pub name: Symbol,
     pub ty: Ty<'gcx>,
     pub res: Option<hir::Res>,
+    /// Whether this is a function attached with a `using for` directive, whose first parameter
+    /// is bound to the accessed expression.
+    pub attached: bool,
 }
 
 impl<'gcx> Member<'gcx> {
     pub fn new(name: Symbol, ty: Ty<'gcx>) -> Self {
-        Self { name, ty, res: None }
+        Self { name, ty, res: None, attached: false }
     }
 
     pub fn with_res(name: Symbol, ty: Ty<'gcx>, res: impl Into<hir::Res>) -> Self {
-        Self { name, ty, res: Some(res.into()) }
+        Self { name, ty, res: Some(res.into()), attached: false }
+    }
+
+    pub fn attached(name: Symbol, ty: Ty<'gcx>, id: hir::FunctionId) -> Self {
+        Self { name, ty, res: Some(hir::ItemId::Function(id).into()), attached: true }
     }
 
     pub fn with_builtin(builtin: Builtin, ty: Ty<'gcx>) -> Self {
@@ -78,7 +88,7 @@ impl<'gcx> Member<'gcx> {
     }
 
     pub fn of_builtin(gcx: Gcx<'gcx>, builtin: Builtin) -> Self {
-        Self { name: builtin.name(), ty: builtin.ty(gcx), res: None }
+        Self::with_builtin(builtin, builtin.ty(gcx))
     }
 
     pub fn of_builtins(
@@ -135,17 +145,48 @@ pub(crate) fn contract(gcx: Gcx<'_>, id: hir::ContractId) -> MemberListOwned<'_>
         .collect()
 }
 
+/// `super.f`: the implemented internal and public functions of the bases of the given contract,
+/// as internal functions.
+///
+/// Functions are looked up in the linearization starting after the contract itself, so only the
+/// most derived function with a given signature is included.
+fn super_contract(gcx: Gcx<'_>, id: hir::ContractId) -> MemberListOwned<'_> {
+    let mut members = MemberListOwned::default();
+    for &base in &gcx.hir.contract(id).linearized_bases[1..] {
+        for f_id in gcx.hir.contract(base).functions() {
+            let f = gcx.hir.function(f_id);
+            if !f.is_ordinary()
+                || f.is_getter()
+                || f.body.is_none()
+                || !matches!(f.visibility, hir::Visibility::Internal | hir::Visibility::Public)
+            {
+                continue;
+            }
+            let name = gcx.item_name(f_id).name;
+            let TyKind::FnPtr(f) = gcx.type_of_item(f_id.into()).kind else { unreachable!() };
+            if members.iter().any(|m| m.name == name && m.ty.parameters() == Some(f.parameters)) {
+                continue;
+            }
+            let ty = gcx.mk_ty_fn(
+                f.parameters,
+                f.state_mutability,
+                hir::Visibility::Internal,
+                f.returns,
+            );
+            members.push(Member::with_res(name, ty, hir::ItemId::from(f_id)));
+        }
+    }
+    members
+}
+
 fn function<'gcx>(gcx: Gcx<'gcx>, f: &'gcx TyFnPtr<'gcx>) -> MemberListOwned<'gcx> {
-    let _ = (gcx, f);
-    todo!()
+    if !f.is_external() {
+        return Default::default();
+    }
+    Member::of_builtins(gcx, [Builtin::FunctionSelector, Builtin::FunctionAddress])
 }
 
-fn reference<'gcx>(
-    gcx: Gcx<'gcx>,
-    this: Ty<'gcx>,
-    inner: Ty<'gcx>,
-    loc: DataLocation,
-) -> MemberListOwned<'gcx> {
+fn reference<'gcx>(gcx: Gcx<'gcx>, inner: Ty<'gcx>, loc: DataLocation) -> MemberListOwned<'gcx> {
     match (&inner.kind, loc) {
         (&TyKind::Struct(id), _) => {
             let fields = gcx.hir.strukt(id).fields;
@@ -154,7 +195,14 @@ fn reference<'gcx>(
             fields
                 .iter()
                 .zip(tys)
-                .map(|(&f, &ty)| Member::new(gcx.item_name(f).name, ty.with_loc(gcx, loc)))
+                .map(|(&f, &ty)| {
+                    let ty = if ty.is_ref() || ty.is_reference_type() {
+                        ty.with_loc(gcx, loc)
+                    } else {
+                        ty
+                    };
+                    Member::with_res(gcx.item_name(f).name, ty, hir::ItemId::from(f))
+                })
                 .collect()
         }
         (
@@ -166,11 +214,17 @@ fn reference<'gcx>(
             } else {
                 gcx.types.fixed_bytes(1)
             };
+            // `push()` returns a reference to the new element.
+            let new_element = if inner.is_reference_type() || inner.has_mapping() {
+                inner.with_loc(gcx, DataLocation::Storage)
+            } else {
+                inner
+            };
             vec![
-                Member::new(sym::length, gcx.types.uint(256)),
-                Member::new(sym::push, gcx.mk_builtin_fn(&[this, inner], SM::NonPayable, &[])),
-                Member::new(sym::push, gcx.mk_builtin_fn(&[this], SM::NonPayable, &[inner])),
-                Member::new(kw::Pop, gcx.mk_builtin_fn(&[this], SM::NonPayable, &[])),
+                Member::with_builtin(Builtin::ArrayLength, gcx.types.uint(256)),
+                Member::new(sym::push, gcx.mk_builtin_fn(&[inner], SM::NonPayable, &[])),
+                Member::new(sym::push, gcx.mk_builtin_fn(&[], SM::NonPayable, &[new_element])),
+                Member::new(kw::Pop, gcx.mk_builtin_fn(&[], SM::NonPayable, &[])),
             ]
         }
         (
@@ -184,8 +238,7 @@ fn reference<'gcx>(
 // `Enum.Variant`, `Udvt.wrap`
 fn type_type<'gcx>(gcx: Gcx<'gcx>, ty: Ty<'gcx>) -> MemberListOwned<'gcx> {
     match ty.kind {
-        // TODO: https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/ast/Types.cpp#L3913
-        TyKind::Contract(_) => Default::default(),
+        TyKind::Contract(id) => type_contract_name(gcx, id),
         TyKind::Enum(id) => {
             gcx.hir.enumm(id).variants.iter().map(|v| Member::new(v.name, ty)).collect()
         }
@@ -207,6 +260,97 @@ fn type_type<'gcx>(gcx: Gcx<'gcx>, ty: Ty<'gcx>) -> MemberListOwned<'gcx> {
     }
 }
 
+/// `C.x`, accessed from outside of `C`'s inheritance hierarchy.
+///
+/// Reference: <https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/ast/Types.cpp#L3913>
+fn type_contract_name(gcx: Gcx<'_>, id: hir::ContractId) -> MemberListOwned<'_> {
+    let c = gcx.hir.contract(id);
+    let is_library = c.kind.is_library();
+    let mut members = nested_declarations(gcx, c);
+    for &item in c.items {
+        match item {
+            hir::ItemId::Function(f_id) => {
+                let f = gcx.hir.function(f_id);
+                if !f.is_ordinary() || f.is_getter() {
+                    continue;
+                }
+                // Library functions can be called, contract functions are only accessible
+                // through their external declaration (e.g. `C.f.selector`).
+                let ty = if is_library && f.visibility >= hir::Visibility::Internal {
+                    gcx.type_of_item(item)
+                } else if !is_library && f.is_part_of_external_interface() {
+                    gcx.type_of_item(item).as_externally_callable_function(gcx)
+                } else {
+                    continue;
+                };
+                members.push(Member::with_res(gcx.item_name(item).name, ty, item));
+            }
+            hir::ItemId::Variable(var_id) if gcx.hir.variable(var_id).is_constant() => {
+                members.push(Member::with_res(
+                    gcx.item_name(item).name,
+                    gcx.type_of_item(item),
+                    item,
+                ));
+            }
+            _ => {}
+        }
+    }
+    members
+}
+
+/// `Base.x`, accessed from a contract that inherits from `Base`.
+///
+/// Only declarations in `Base` itself are included, not the ones that it inherits.
+pub(crate) fn base_contract(gcx: Gcx<'_>, id: hir::ContractId) -> MemberListOwned<'_> {
+    let c = gcx.hir.contract(id);
+    let mut members = nested_declarations(gcx, c);
+    for &item in c.items {
+        match item {
+            hir::ItemId::Function(f_id) => {
+                let f = gcx.hir.function(f_id);
+                if !f.is_ordinary()
+                    || f.is_getter()
+                    || !matches!(f.visibility, hir::Visibility::Internal | hir::Visibility::Public)
+                {
+                    continue;
+                }
+                let ty = gcx.type_of_item(item);
+                let TyKind::FnPtr(f) = ty.kind else { unreachable!() };
+                let ty = gcx.mk_ty_fn(
+                    f.parameters,
+                    f.state_mutability,
+                    hir::Visibility::Internal,
+                    f.returns,
+                );
+                members.push(Member::with_res(gcx.item_name(item).name, ty, item));
+            }
+            hir::ItemId::Variable(var_id)
+                if gcx.hir.variable(var_id).visibility >= Some(hir::Visibility::Internal) =>
+            {
+                members.push(Member::with_res(
+                    gcx.item_name(item).name,
+                    gcx.type_of_item(item),
+                    item,
+                ));
+            }
+            _ => {}
+        }
+    }
+    members
+}
+
+/// Types, events, and errors declared in the given contract.
+fn nested_declarations<'gcx>(gcx: Gcx<'gcx>, c: &hir::Contract<'_>) -> MemberListOwned<'gcx> {
+    c.items
+        .iter()
+        .filter(|item| !matches!(item, hir::ItemId::Function(_) | hir::ItemId::Variable(_)))
+        .map(|&item| {
+            let res = hir::Res::Item(item);
+            Member::with_res(gcx.item_name(item).name, gcx.type_of_res(res), res)
+        })
+        .collect()
+}
+
 // `type(T)`
 fn meta<'gcx>(gcx: Gcx<'gcx>, ty: Ty<'gcx>) -> MemberListOwned<'gcx> {
     match ty.kind {
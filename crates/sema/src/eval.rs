@@ -1,17 +1,30 @@
-use crate::{hir, ty::Gcx};
-use alloy_primitives::U256;
-use solar_ast::LitKind;
-use solar_interface::{diagnostics::ErrorGuaranteed, Span};
+use crate::{
+    builtins::Builtin,
+    hir,
+    ty::{Gcx, Ty, TyKind},
+};
+use alloy_primitives::{keccak256, U256};
+use num_bigint::{BigInt, Sign};
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use sha2::{Digest, Sha256};
+use solar_ast::{ElementaryType, LitKind};
+use solar_interface::{diagnostics::ErrorGuaranteed, sym, Ident, Span};
 use std::fmt;
 
 const RECURSION_LIMIT: usize = 64;
 
-// TODO: `convertType` for truncating and extending correctly: https://github.com/ethereum/solidity/blob/de1a017ccb935d149ed6bcbdb730d89883f8ce02/libsolidity/analysis/ConstantEvaluator.cpp#L234
+/// The maximum number of bits that the numerator or denominator of an intermediate untyped value
+/// can have.
+///
+/// Same as solc.
+const MAX_LITERAL_BITS: u64 = 4096;
 
 /// Evaluates simple constants.
 ///
-/// This only supports basic arithmetic and logical operations, and does not support more complex
-/// operations like function calls or memory allocation.
+/// This only supports basic arithmetic and logical operations, type conversions, and the pure
+/// hashing and encoding builtins, and does not support more complex operations like function calls
+/// or memory allocation.
 ///
 /// This is only supposed to be used for array sizes and other simple constants.
 pub struct ConstantEvaluator<'gcx> {
@@ -19,7 +32,7 @@ pub struct ConstantEvaluator<'gcx> {
     depth: usize,
 }
 
-type EvalResult<'gcx> = Result<IntScalar, EvalError>;
+type EvalResult<T = Value> = Result<T, EvalError>;
 
 impl<'gcx> ConstantEvaluator<'gcx> {
     /// Creates a new constant evaluator.
@@ -32,20 +45,36 @@ impl<'gcx> ConstantEvaluator<'gcx> {
         self.try_eval(expr).map_err(|err| self.emit_eval_error(expr, err))
     }
 
-    /// Evaluates the given expression, returning an error if it fails.
-    pub fn try_eval(&mut self, expr: &hir::Expr<'_>) -> EvalResult<'gcx> {
-        self.depth += 1;
-        if self.depth > RECURSION_LIMIT {
-            return Err(EE::RecursionLimitReached.spanned(expr.span));
+    /// Evaluates the given expression, returning an error if it fails or if the value is negative.
+    pub fn try_eval(&mut self, expr: &hir::Expr<'_>) -> EvalResult<IntScalar> {
+        let value = self.eval_value(expr)?;
+        let value = value.as_int().map_err(|kind| kind.spanned(expr.span))?;
+        if value.int.is_negative() {
+            return Err(EE::ArithmeticOverflow.spanned(expr.span));
         }
-        let mut res = self.eval_expr(expr);
-        if let Err(e) = &mut res {
-            if e.span.is_dummy() {
-                e.span = expr.span;
+        value.to_scalar().ok_or_else(|| EE::IntTooBig.spanned(expr.span))
+    }
+
+    /// Evaluates the initializer of the given constant variable, converted to its declared type,
+    /// returning an error if it fails.
+    ///
+    /// Negative values are returned in two's complement representation.
+    pub fn try_eval_variable(&mut self, id: hir::VariableId) -> EvalResult<ConstValue<'gcx>> {
+        let var = self.gcx.hir.variable(id);
+        let value = self.eval_variable(id).map_err(|mut err| {
+            if err.span.is_dummy() {
+                err.span = var.initializer.map_or(var.span, |init| init.span);
             }
+            err
+        })?;
+        match value {
+            Value::Int(value) => value
+                .to_scalar()
+                .map(ConstValue::Int)
+                .ok_or_else(|| EE::IntTooBig.spanned(var.span)),
+            Value::Rational(_) => Err(EE::NotAnInteger.spanned(var.span)),
+            Value::Bytes(bytes) => Ok(ConstValue::Bytes(self.gcx.bump().alloc_slice_copy(&bytes))),
         }
-        self.depth = self.depth.checked_sub(1).unwrap();
-        res
     }
 
     /// Emits a diagnostic for the given evaluation error.
@@ -59,46 +88,60 @@ impl<'gcx> ConstantEvaluator<'gcx> {
         }
     }
 
-    fn eval_expr(&mut self, expr: &hir::Expr<'_>) -> EvalResult<'gcx> {
+    fn eval_value(&mut self, expr: &hir::Expr<'_>) -> EvalResult {
+        self.depth += 1;
+        if self.depth > RECURSION_LIMIT {
+            return Err(EE::RecursionLimitReached.spanned(expr.span));
+        }
+        let mut res = self.eval_expr(expr);
+        if let Err(e) = &mut res {
+            if e.span.is_dummy() {
+                e.span = expr.span;
+            }
+        }
+        self.depth = self.depth.checked_sub(1).unwrap();
+        res
+    }
+
+    fn eval_expr(&mut self, expr: &hir::Expr<'_>) -> EvalResult {
         let expr = expr.peel_parens();
         match expr.kind {
             // hir::ExprKind::Array(_) => unimplemented!(),
             // hir::ExprKind::Assign(_, _, _) => unimplemented!(),
             hir::ExprKind::Binary(l, bin_op, r) => {
-                let l = self.try_eval(l)?;
-                let r = self.try_eval(r)?;
+                let l = self.eval_value(l)?;
+                let r = self.eval_value(r)?;
                 l.binop(&r, bin_op.kind).map_err(Into::into)
             }
-            // hir::ExprKind::Call(_, _) => unimplemented!(),
+            hir::ExprKind::Call(callee, ref args, None) => self.eval_call(callee, args),
             // hir::ExprKind::CallOptions(_, _) => unimplemented!(),
             // hir::ExprKind::Delete(_) => unimplemented!(),
             hir::ExprKind::Ident(res) => {
                 // Ignore invalid overloads since they will get correctly detected later.
-                let Some(v) = res.iter().find_map(|res| res.as_variable()) else {
+                let Some(id) = res.iter().find_map(|res| res.as_variable()) else {
                     return Err(EE::NonConstantVar.into());
                 };
-
-                let v = self.gcx.hir.variable(v);
-                if v.mutability != Some(hir::VarMut::Constant) {
-                    return Err(EE::NonConstantVar.into());
-                }
-                self.try_eval(v.initializer.expect("constant variable has no initializer"))
+                self.eval_variable(id)
             }
             // hir::ExprKind::Index(_, _) => unimplemented!(),
             // hir::ExprKind::Slice(_, _, _) => unimplemented!(),
             hir::ExprKind::Lit(lit) => self.eval_lit(lit),
-            // hir::ExprKind::Member(_, _) => unimplemented!(),
+            hir::ExprKind::Member(base, member) => self.eval_member(base, member),
             // hir::ExprKind::New(_) => unimplemented!(),
             // hir::ExprKind::Payable(_) => unimplemented!(),
             hir::ExprKind::Ternary(cond, t, f) => {
-                let cond = self.try_eval(cond)?;
-                Ok(if cond.to_bool() { self.try_eval(t)? } else { self.try_eval(f)? })
+                let cond = self.eval_value(cond)?;
+                if cond.as_int()?.to_bool() {
+                    self.eval_value(t)
+                } else {
+                    self.eval_value(f)
+                }
             }
             // hir::ExprKind::Tuple(_) => unimplemented!(),
             // hir::ExprKind::TypeCall(_) => unimplemented!(),
             // hir::ExprKind::Type(_) => unimplemented!(),
             hir::ExprKind::Unary(un_op, v) => {
-                let v = self.try_eval(v)?;
+                let v = self.eval_value(v)?;
                 v.unop(un_op.kind).map_err(Into::into)
             }
             hir::ExprKind::Err(guar) => Err(EE::AlreadyEmitted(guar).into()),
@@ -106,25 +149,160 @@ impl<'gcx> ConstantEvaluator<'gcx> {
         }
     }
 
-    fn eval_lit(&mut self, lit: &hir::Lit) -> EvalResult<'gcx> {
+    fn eval_variable(&mut self, id: hir::VariableId) -> EvalResult {
+        let v = self.gcx.hir.variable(id);
+        if v.mutability != Some(hir::VarMut::Constant) {
+            return Err(EE::NonConstantVar.into());
+        }
+        let init = v.initializer.expect("constant variable has no initializer");
+        let value = self.eval_value(init)?;
+        // The value is implicitly converted to the declared type of the variable.
+        let ty = self.gcx.type_of_item(id.into());
+        match value {
+            // Literals that do not fit in 256 bits are too big for any type.
+            Value::Int(value) if value.ty.is_none() && !value.fits_word() => {
+                Err(EE::IntTooBig.spanned(init.span))
+            }
+            Value::Int(value) => {
+                IntValue::new(value.int, ValueType::from_ty(ty)).map(Value::Int).map_err(Into::into)
+            }
+            // Rational values can only be assigned to fixed point types.
+            Value::Rational(_) if ValueType::from_ty(ty).is_some() => Err(EE::NotAnInteger.into()),
+            Value::Rational(_) => Ok(value),
+            Value::Bytes(_) => value.convert(ty).map_err(Into::into),
+        }
+    }
+
+    /// Evaluates elementary type conversions, and calls to the pure hashing and encoding builtins.
+    fn eval_call(&mut self, callee: &hir::Expr<'_>, args: &hir::CallArgs<'_>) -> EvalResult {
+        let builtin = match callee.peel_parens().kind {
+            hir::ExprKind::Type(ref ty) => {
+                let mut args = args.exprs();
+                let (Some(arg), None) = (args.next(), args.next()) else {
+                    return Err(EE::UnsupportedExpr.into());
+                };
+                let value = self.eval_value(arg)?;
+                return value.convert(self.gcx.type_of_hir_ty(ty)).map_err(Into::into);
+            }
+            hir::ExprKind::Ident(res) => res.iter().find_map(|res| match *res {
+                hir::Res::Builtin(builtin) => Some(builtin),
+                _ => None,
+            }),
+            hir::ExprKind::Member(base, member) => match base.peel_parens().kind {
+                hir::ExprKind::Ident(res)
+                    if member.name == sym::encodePacked
+                        && res.contains(&hir::Res::Builtin(Builtin::Abi)) =>
+                {
+                    Some(Builtin::AbiEncodePacked)
+                }
+                hir::ExprKind::Type(hir::Type {
+                    kind: hir::TypeKind::Elementary(ElementaryType::Bytes | ElementaryType::String),
+                    ..
+                }) if member.name == sym::concat => Some(Builtin::BytesConcat),
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(builtin) = builtin else { return Err(EE::UnsupportedExpr.into()) };
+
+        let args = args.exprs().map(|arg| self.eval_value(arg)).collect::<EvalResult<Vec<_>>>()?;
+        match builtin {
+            Builtin::Keccak256 | Builtin::Sha256 => {
+                let [Value::Bytes(data)] = &args[..] else {
+                    return Err(EE::UnsupportedExpr.into());
+                };
+                let hash: [u8; 32] = if builtin == Builtin::Keccak256 {
+                    keccak256(data).0
+                } else {
+                    Sha256::digest(data).into()
+                };
+                Ok(Value::Int(IntValue::from_be_bytes(&hash, ValueType::FixedBytes(32))))
+            }
+            // `bytes.concat` and `string.concat` only accept byte arrays and fixed bytes, for
+            // which the concatenation is the same as the packed encoding.
+            Builtin::AbiEncodePacked | Builtin::BytesConcat => {
+                let mut out = Vec::new();
+                for arg in &args {
+                    arg.encode_packed(&mut out)?;
+                }
+                Ok(Value::Bytes(out))
+            }
+            _ => Err(EE::UnsupportedExpr.into()),
+        }
+    }
+
+    /// Evaluates `type(T).min`, `type(T).max`, enum variants, and qualified constant variables.
+    fn eval_member(&mut self, base: &hir::Expr<'_>, member: Ident) -> EvalResult {
+        let gcx = self.gcx;
+        match base.peel_parens().kind {
+            hir::ExprKind::TypeCall(ref ty) => {
+                let ty = gcx.type_of_hir_ty(ty);
+                // Like literals, these are untyped so that `type(uint8).max + 1` can be evaluated.
+                let (min, max) = match ty.kind {
+                    TyKind::Elementary(ElementaryType::UInt(_) | ElementaryType::Int(_)) => {
+                        let Some(ValueType::Int(int)) = ValueType::from_ty(ty) else {
+                            unreachable!()
+                        };
+                        (int.min(), int.max())
+                    }
+                    TyKind::Enum(id) => {
+                        (BigInt::zero(), BigInt::from(gcx.hir.enumm(id).variants.len()) - 1)
+                    }
+                    _ => return Err(EE::UnsupportedExpr.into()),
+                };
+                match member.name {
+                    sym::min => Ok(Value::Int(IntValue::untyped(min))),
+                    sym::max => Ok(Value::Int(IntValue::untyped(max))),
+                    _ => Err(EE::UnsupportedExpr.into()),
+                }
+            }
+            hir::ExprKind::Ident(res) => {
+                for res in res {
+                    match *res {
+                        hir::Res::Item(hir::ItemId::Enum(id)) => {
+                            let variants = gcx.hir.enumm(id).variants;
+                            if let Some(i) = variants.iter().position(|v| v.name == member.name) {
+                                return Ok(Value::Int(IntValue::untyped(BigInt::from(i))));
+                            }
+                        }
+                        hir::Res::Item(hir::ItemId::Contract(id)) => {
+                            let c = gcx.hir.contract(id);
+                            let var = c.linearized_bases.iter().find_map(|&base| {
+                                gcx.hir.contract(base).variables().find(|&v| {
+                                    gcx.hir.variable(v).name.is_some_and(|n| n.name == member.name)
+                                })
+                            });
+                            if let Some(var) = var {
+                                return self.eval_variable(var);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Err(EE::UnsupportedExpr.into())
+            }
+            _ => Err(EE::UnsupportedExpr.into()),
+        }
+    }
+
+    fn eval_lit(&mut self, lit: &hir::Lit) -> EvalResult {
         match lit.kind {
-            // LitKind::Str(str_kind, arc) => todo!(),
+            LitKind::Str(_, ref bytes) => Ok(Value::Bytes(bytes.to_vec())),
             LitKind::Number(ref big_int) => {
-                let (_, bytes) = big_int.to_bytes_be();
-                if bytes.len() > 32 {
-                    return Err(EE::IntTooBig.into());
-                }
-                Ok(IntScalar::from_be_bytes(&bytes))
+                IntValue::new(big_int.clone(), None).map(Value::Int).map_err(Into::into)
             }
-            // LitKind::Rational(ratio) => todo!(),
-            LitKind::Address(address) => Ok(IntScalar::from_be_bytes(address.as_slice())),
-            LitKind::Bool(bool) => Ok(IntScalar::from_be_bytes(&[bool as u8])),
+            LitKind::Rational(ref ratio) => Value::from_rational(ratio.clone()).map_err(Into::into),
+            LitKind::Address(address) => {
+                Ok(Value::Int(IntValue::from_be_bytes(address.as_slice(), ValueType::Address)))
+            }
+            LitKind::Bool(bool) => Ok(Value::Int(IntValue::from_bool(bool))),
             LitKind::Err(guar) => Err(EE::AlreadyEmitted(guar).into()),
-            _ => Err(EE::UnsupportedLiteral.into()),
         }
     }
 }
 
+/// An integer value, returned by [`ConstantEvaluator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct IntScalar {
     pub data: U256,
 }
@@ -152,57 +330,522 @@ impl IntScalar {
     pub fn to_bool(&self) -> bool {
         !self.data.is_zero()
     }
+}
+
+/// A constant value, returned by [`ConstantEvaluator::try_eval_variable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstValue<'a> {
+    /// An integer, boolean, address or fixed bytes value.
+    ///
+    /// Fixed bytes are represented by their integer value, e.g. `bytes2("ab")` is `0x6162`.
+    Int(IntScalar),
+    /// A `bytes` or `string` value.
+    Bytes(&'a [u8]),
+}
+
+impl<'a> ConstValue<'a> {
+    /// Returns the integer value, if any.
+    pub fn as_int(&self) -> Option<IntScalar> {
+        match *self {
+            Self::Int(int) => Some(int),
+            Self::Bytes(_) => None,
+        }
+    }
+
+    /// Returns the byte array value, if any.
+    pub fn as_bytes(&self) -> Option<&'a [u8]> {
+        match *self {
+            Self::Int(_) => None,
+            Self::Bytes(bytes) => Some(bytes),
+        }
+    }
+}
+
+/// The type of a typed [`IntValue`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ValueType {
+    /// `uintN` or `intN`.
+    Int(IntType),
+    /// `bytesN`.
+    FixedBytes(u8),
+    /// `address` or `address payable`.
+    Address,
+    /// `bool`.
+    Bool,
+}
+
+impl ValueType {
+    fn from_ty(ty: Ty<'_>) -> Option<Self> {
+        match ty.kind {
+            TyKind::Elementary(ElementaryType::UInt(size)) => {
+                Some(Self::Int(IntType { signed: false, bits: size.bits() }))
+            }
+            TyKind::Elementary(ElementaryType::Int(size)) => {
+                Some(Self::Int(IntType { signed: true, bits: size.bits() }))
+            }
+            TyKind::Elementary(ElementaryType::FixedBytes(size)) => {
+                Some(Self::FixedBytes(size.bytes()))
+            }
+            TyKind::Elementary(ElementaryType::Address(_)) => Some(Self::Address),
+            TyKind::Elementary(ElementaryType::Bool) => Some(Self::Bool),
+            _ => None,
+        }
+    }
+
+    /// Returns the size of this type in bytes, as in its packed encoding.
+    fn size(self) -> usize {
+        match self {
+            Self::Int(int) => int.bits as usize / 8,
+            Self::FixedBytes(bytes) => bytes as usize,
+            Self::Address => 20,
+            Self::Bool => 1,
+        }
+    }
+
+    /// Returns the range of values of this type, as an integer type.
+    fn range(self) -> IntType {
+        match self {
+            Self::Int(int) => int,
+            Self::Bool => IntType { signed: false, bits: 1 },
+            _ => IntType { signed: false, bits: self.size() as u16 * 8 },
+        }
+    }
+}
+
+/// An integer type: `uintN` or `intN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct IntType {
+    signed: bool,
+    bits: u16,
+}
+
+impl IntType {
+    fn min(self) -> BigInt {
+        if self.signed {
+            -(BigInt::one() << (self.bits - 1))
+        } else {
+            BigInt::zero()
+        }
+    }
+
+    fn max(self) -> BigInt {
+        let bits = if self.signed { self.bits - 1 } else { self.bits };
+        (BigInt::one() << bits) - 1
+    }
+
+    fn contains(self, value: &BigInt) -> bool {
+        self.min() <= *value && *value <= self.max()
+    }
+
+    /// Truncates or sign-extends `int` to this type, keeping its lowest `bits` bits.
+    fn wrap(self, int: &BigInt) -> BigInt {
+        let int: BigInt = int & ((BigInt::one() << self.bits) - 1);
+        if self.signed && int.bit(self.bits as u64 - 1) {
+            int - (BigInt::one() << self.bits)
+        } else {
+            int
+        }
+    }
+}
+
+/// A value computed by the evaluator.
+#[derive(Clone, Debug)]
+enum Value {
+    /// An integer, boolean, address or fixed bytes value.
+    Int(IntValue),
+    /// A rational number that is not an integer.
+    ///
+    /// These can only be produced by rational literals and operations on literals, and are
+    /// therefore always untyped.
+    Rational(BigRational),
+    /// A byte array: a string or hex literal, or a `bytes` or `string` value.
+    Bytes(Vec<u8>),
+}
+
+impl Value {
+    /// Creates an untyped value from a rational number, normalizing integers.
+    fn from_rational(value: BigRational) -> Result<Self, EE> {
+        if value.is_integer() {
+            return IntValue::new(value.to_integer(), None).map(Self::Int);
+        }
+        if value.numer().bits() > MAX_LITERAL_BITS || value.denom().bits() > MAX_LITERAL_BITS {
+            return Err(EE::IntTooBig);
+        }
+        Ok(Self::Rational(value))
+    }
+
+    fn as_int(&self) -> Result<&IntValue, EE> {
+        match self {
+            Self::Int(int) => Ok(int),
+            Self::Rational(_) => Err(EE::NotAnInteger),
+            Self::Bytes(_) => Err(EE::NonIntValue),
+        }
+    }
+
+    /// Returns the exact value of an untyped number.
+    fn as_untyped_rational(&self) -> Option<BigRational> {
+        match self {
+            Self::Int(IntValue { int, ty: None }) => Some(BigRational::from_integer(int.clone())),
+            Self::Rational(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /// Applies the given unary operation to this value.
+    fn unop(&self, op: hir::UnOpKind) -> Result<Self, EE> {
+        match self {
+            Self::Rational(value) if op == hir::UnOpKind::Neg => Ok(Self::Rational(-value)),
+            _ => self.as_int()?.unop(op).map(Self::Int),
+        }
+    }
+
+    /// Applies the given binary operation to this value.
+    ///
+    /// Operations on untyped values are evaluated exactly with [`fold_rationals`], while operations
+    /// involving a typed value require both operands to be integers.
+    fn binop(&self, r: &Self, op: hir::BinOpKind) -> Result<Self, EE> {
+        use hir::BinOpKind::*;
+
+        if let (Some(l), Some(r)) = (self.as_untyped_rational(), r.as_untyped_rational()) {
+            return match op {
+                Lt => Ok(Self::Int(IntValue::from_bool(l < r))),
+                Le => Ok(Self::Int(IntValue::from_bool(l <= r))),
+                Gt => Ok(Self::Int(IntValue::from_bool(l > r))),
+                Ge => Ok(Self::Int(IntValue::from_bool(l >= r))),
+                Eq => Ok(Self::Int(IntValue::from_bool(l == r))),
+                Ne => Ok(Self::Int(IntValue::from_bool(l != r))),
+                Or | And => Err(EE::UnsupportedExpr),
+                _ => fold_rationals(&l, op, &r).and_then(Self::from_rational),
+            };
+        }
+        self.as_int()?.binop(r.as_int()?, op).map(Self::Int)
+    }
+
+    /// Explicitly converts this value to the given type.
+    ///
+    /// Byte arrays can only be converted to `bytes`, `string`, or to fixed bytes, in which case
+    /// they are truncated or padded on the right.
+    fn convert(&self, to: Ty<'_>) -> Result<Self, EE> {
+        match (self, &to.peel_refs().kind) {
+            (Self::Int(int), _) => int.convert(to).map(Self::Int),
+            (Self::Rational(_), _) => Err(EE::NotAnInteger),
+            (
+                Self::Bytes(_),
+                &TyKind::Elementary(ElementaryType::Bytes | ElementaryType::String),
+            ) => Ok(self.clone()),
+            (Self::Bytes(bytes), &TyKind::Elementary(ElementaryType::FixedBytes(size))) => {
+                if bytes.len() > size.bytes() as usize {
+                    return Err(EE::BytesTooLong);
+                }
+                let mut padded = bytes.clone();
+                padded.resize(size.bytes() as usize, 0);
+                Ok(Self::Int(IntValue::from_be_bytes(&padded, ValueType::FixedBytes(size.bytes()))))
+            }
+            (Self::Bytes(_), _) => Err(EE::UnsupportedExpr),
+        }
+    }
+
+    /// Appends the packed ABI encoding of this value to `out`.
+    ///
+    /// Untyped values, such as number literals, do not have a packed encoding.
+    fn encode_packed(&self, out: &mut Vec<u8>) -> Result<(), EE> {
+        match self {
+            Self::Int(IntValue { int, ty: Some(ty) }) => {
+                let size = ty.size();
+                let int = IntType { signed: false, bits: size as u16 * 8 }.wrap(int);
+                let (_, bytes) = int.to_bytes_be();
+                out.resize(out.len() + size - bytes.len(), 0);
+                out.extend_from_slice(&bytes);
+            }
+            Self::Int(IntValue { ty: None, .. }) | Self::Rational(_) => {
+                return Err(EE::UnsupportedExpr)
+            }
+            Self::Bytes(bytes) => out.extend_from_slice(bytes),
+        }
+        Ok(())
+    }
+}
+
+/// An exact integer value computed by the evaluator.
+///
+/// Values derived from constant variables of integer type are typed, and must fit in their type.
+/// Literals are untyped, and are only bounded by [`MAX_LITERAL_BITS`], like in solc.
+#[derive(Clone, Debug)]
+struct IntValue {
+    int: BigInt,
+    ty: Option<ValueType>,
+}
+
+impl IntValue {
+    fn new(int: BigInt, ty: Option<ValueType>) -> Result<Self, EE> {
+        match ty {
+            Some(ty) if !ty.range().contains(&int) => Err(EE::ArithmeticOverflow),
+            None if int.bits() > MAX_LITERAL_BITS => Err(EE::IntTooBig),
+            _ => Ok(Self { int, ty }),
+        }
+    }
+
+    fn untyped(int: BigInt) -> Self {
+        Self { int, ty: None }
+    }
+
+    fn from_bool(value: bool) -> Self {
+        Self { int: BigInt::from(value as u8), ty: Some(ValueType::Bool) }
+    }
+
+    fn from_be_bytes(bytes: &[u8], ty: ValueType) -> Self {
+        Self { int: BigInt::from_bytes_be(Sign::Plus, bytes), ty: Some(ty) }
+    }
+
+    fn to_bool(&self) -> bool {
+        !self.int.is_zero()
+    }
+
+    /// Returns `true` if the value fits in a 256-bit signed or unsigned integer.
+    fn fits_word(&self) -> bool {
+        let uint = IntType { signed: false, bits: 256 };
+        let int = IntType { signed: true, bits: 256 };
+        uint.contains(&self.int) || int.contains(&self.int)
+    }
+
+    /// Converts the value to a 256-bit word, using two's complement for negative values.
+    fn to_scalar(&self) -> Option<IntScalar> {
+        let (sign, bytes) = self.int.to_bytes_be();
+        if bytes.len() > 32 {
+            return None;
+        }
+        let magnitude = U256::from_be_slice(&bytes);
+        if sign == Sign::Minus {
+            if magnitude > U256::from(1) << 255 {
+                return None;
+            }
+            return Some(IntScalar::new(magnitude.wrapping_neg()));
+        }
+        Some(IntScalar::new(magnitude))
+    }
 
     /// Applies the given unary operation to this value.
-    pub fn unop(&self, op: hir::UnOpKind) -> Result<Self, EE> {
-        Ok(match op {
+    fn unop(&self, op: hir::UnOpKind) -> Result<Self, EE> {
+        let int = match op {
             hir::UnOpKind::PreInc
             | hir::UnOpKind::PreDec
             | hir::UnOpKind::PostInc
             | hir::UnOpKind::PostDec => return Err(EE::UnsupportedUnaryOp),
-            hir::UnOpKind::Not | hir::UnOpKind::BitNot => Self::new(!self.data),
-            hir::UnOpKind::Neg => Self::new(self.data.wrapping_neg()),
-        })
+            hir::UnOpKind::Not => return Ok(Self::from_bool(!self.to_bool())),
+            hir::UnOpKind::BitNot => match self.ty.map(ValueType::range) {
+                Some(ty) if !ty.signed => ty.max() ^ &self.int,
+                _ => -&self.int - 1,
+            },
+            hir::UnOpKind::Neg => -&self.int,
+        };
+        Self::new(int, self.ty)
+    }
+
+    /// Explicitly converts this value to the given type, truncating or extending it like solc.
+    ///
+    /// Reference: <https://github.com/ethereum/solidity/blob/de1a017ccb935d149ed6bcbdb730d89883f8ce02/libsolidity/analysis/ConstantEvaluator.cpp#L234>
+    fn convert(&self, to: Ty<'_>) -> Result<Self, EE> {
+        let Some(to_ty) = ValueType::from_ty(to) else { return Err(EE::UnsupportedExpr) };
+        let int = match (self.ty, to_ty) {
+            // Fixed bytes are left-aligned, so they are truncated or padded on the right.
+            (Some(ValueType::FixedBytes(from)), ValueType::FixedBytes(to)) => {
+                if to < from {
+                    &self.int >> (8 * (from - to) as usize)
+                } else {
+                    &self.int << (8 * (to - from) as usize)
+                }
+            }
+            _ => to_ty.range().wrap(&self.int),
+        };
+        Ok(Self { int, ty: Some(to_ty) })
     }
 
     /// Applies the given binary operation to this value.
-    pub fn binop(&self, r: &Self, op: hir::BinOpKind) -> Result<Self, EE> {
+    fn binop(&self, r: &Self, op: hir::BinOpKind) -> Result<Self, EE> {
         let l = self;
-        Ok(match op {
-            hir::BinOpKind::Lt => Self::from_bool(l.data < r.data),
-            hir::BinOpKind::Le => Self::from_bool(l.data <= r.data),
-            hir::BinOpKind::Gt => Self::from_bool(l.data > r.data),
-            hir::BinOpKind::Ge => Self::from_bool(l.data >= r.data),
-            hir::BinOpKind::Eq => Self::from_bool(l.data == r.data),
-            hir::BinOpKind::Ne => Self::from_bool(l.data != r.data),
-            hir::BinOpKind::Or | hir::BinOpKind::BitOr => Self::new(l.data | r.data),
-            hir::BinOpKind::And | hir::BinOpKind::BitAnd => Self::new(l.data & r.data),
-            hir::BinOpKind::BitXor => Self::new(l.data ^ r.data),
-            hir::BinOpKind::Shr => {
-                Self::new(l.data.wrapping_shr(r.data.try_into().unwrap_or(usize::MAX)))
+        // Untyped operands are implicitly converted to the type of the other operand, except for
+        // the right-hand side of shifts and exponentiation.
+        let ty = match op {
+            hir::BinOpKind::Shl
+            | hir::BinOpKind::Shr
+            | hir::BinOpKind::Sar
+            | hir::BinOpKind::Pow => l.ty,
+            _ => l.ty.or(r.ty),
+        };
+        let shift_amount = || -> Result<usize, EE> {
+            if r.int.is_negative() {
+                return Err(EE::ArithmeticOverflow);
             }
+            Ok(r.int.to_usize().filter(|&n| n as u64 <= MAX_LITERAL_BITS).unwrap_or(usize::MAX))
+        };
+        let int = match op {
+            hir::BinOpKind::Lt => return Ok(Self::from_bool(l.int < r.int)),
+            hir::BinOpKind::Le => return Ok(Self::from_bool(l.int <= r.int)),
+            hir::BinOpKind::Gt => return Ok(Self::from_bool(l.int > r.int)),
+            hir::BinOpKind::Ge => return Ok(Self::from_bool(l.int >= r.int)),
+            hir::BinOpKind::Eq => return Ok(Self::from_bool(l.int == r.int)),
+            hir::BinOpKind::Ne => return Ok(Self::from_bool(l.int != r.int)),
+            hir::BinOpKind::Or => return Ok(Self::from_bool(l.to_bool() || r.to_bool())),
+            hir::BinOpKind::And => return Ok(Self::from_bool(l.to_bool() && r.to_bool())),
+            hir::BinOpKind::BitOr => &l.int | &r.int,
+            hir::BinOpKind::BitAnd => &l.int & &r.int,
+            hir::BinOpKind::BitXor => &l.int ^ &r.int,
+            hir::BinOpKind::Shr | hir::BinOpKind::Sar => match shift_amount()? {
+                usize::MAX if l.int.is_negative() => BigInt::from(-1),
+                usize::MAX => BigInt::zero(),
+                n => &l.int >> n,
+            },
             hir::BinOpKind::Shl => {
-                Self::new(l.data.wrapping_shl(r.data.try_into().unwrap_or(usize::MAX)))
+                let n = shift_amount()?;
+                if l.int.is_zero() {
+                    BigInt::zero()
+                } else if n == usize::MAX {
+                    return Err(if ty.is_some() { EE::ArithmeticOverflow } else { EE::IntTooBig });
+                } else if let Some(ty) = ty {
+                    // Shifts of typed values truncate instead of overflowing.
+                    return Ok(Self { int: ty.range().wrap(&(&l.int << n)), ty: Some(ty) });
+                } else {
+                    &l.int << n
+                }
             }
-            hir::BinOpKind::Sar => {
-                Self::new(l.data.arithmetic_shr(r.data.try_into().unwrap_or(usize::MAX)))
+            hir::BinOpKind::Add => &l.int + &r.int,
+            hir::BinOpKind::Sub => &l.int - &r.int,
+            hir::BinOpKind::Mul => &l.int * &r.int,
+            hir::BinOpKind::Pow => {
+                if r.int.is_negative() {
+                    return Err(EE::UnsupportedExpr);
+                }
+                if l.int.is_zero() || l.int.magnitude().is_one() {
+                    let odd = r.int.bit(0);
+                    let int = if r.int.is_zero() || (l.int.is_negative() && !odd) {
+                        BigInt::one()
+                    } else {
+                        l.int.clone()
+                    };
+                    return Self::new(int, ty);
+                }
+                let exp = r
+                    .int
+                    .to_u64()
+                    .filter(|&exp| exp.saturating_mul(l.int.bits()) <= MAX_LITERAL_BITS)
+                    .ok_or(if ty.is_some() { EE::ArithmeticOverflow } else { EE::IntTooBig })?;
+                l.int.pow(exp as u32)
             }
-            hir::BinOpKind::Add => {
-                Self::new(l.data.checked_add(r.data).ok_or(EE::ArithmeticOverflow)?)
+            hir::BinOpKind::Div => {
+                if r.int.is_zero() {
+                    return Err(EE::DivisionByZero);
+                }
+                &l.int / &r.int
             }
-            hir::BinOpKind::Sub => {
-                Self::new(l.data.checked_sub(r.data).ok_or(EE::ArithmeticOverflow)?)
+            hir::BinOpKind::Rem => {
+                if r.int.is_zero() {
+                    return Err(EE::DivisionByZero);
+                }
+                &l.int % &r.int
             }
-            hir::BinOpKind::Pow => {
-                Self::new(l.data.checked_pow(r.data).ok_or(EE::ArithmeticOverflow)?)
+        };
+        Self::new(int, ty)
+    }
+}
+
+/// Folds a binary arithmetic or bitwise operation on two untyped values, following solc's
+/// `RationalNumberType`.
+///
+/// Division is exact, while the remainder is computed with the quotient truncated towards zero.
+/// Exponents, shifts and bitwise operations require integer operands.
+pub(crate) fn fold_rationals(
+    l: &BigRational,
+    op: hir::BinOpKind,
+    r: &BigRational,
+) -> Result<BigRational, EvalErrorKind> {
+    use hir::BinOpKind::*;
+
+    let integers = || {
+        if !l.is_integer() || !r.is_integer() {
+            return Err(EE::NotAnInteger);
+        }
+        Ok((l.to_integer(), r.to_integer()))
+    };
+    let shift_amount = |r: &BigInt| {
+        if r.is_negative() {
+            return Err(EE::ArithmeticOverflow);
+        }
+        Ok(r.to_usize().filter(|&n| n as u64 <= MAX_LITERAL_BITS))
+    };
+    let value = match op {
+        Add => l + r,
+        Sub => l - r,
+        Mul => l * r,
+        Div => {
+            if r.is_zero() {
+                return Err(EE::DivisionByZero);
+            }
+            l / r
+        }
+        Rem => {
+            if r.is_zero() {
+                return Err(EE::DivisionByZero);
+            }
+            l - (l / r).trunc() * r
+        }
+        Pow => {
+            if !r.is_integer() {
+                return Err(EE::NotAnInteger);
             }
-            hir::BinOpKind::Mul => {
-                Self::new(l.data.checked_mul(r.data).ok_or(EE::ArithmeticOverflow)?)
+            let exp = r.to_integer();
+            if l.is_zero() || l.abs().is_one() {
+                // Any exponent is valid for these bases.
+                if l.is_zero() && exp.is_negative() {
+                    return Err(EE::DivisionByZero);
+                }
+                let odd = exp.bit(0);
+                return Ok(if exp.is_zero() || (l.is_negative() && !odd) {
+                    BigRational::one()
+                } else {
+                    l.clone()
+                });
+            }
+            let base_bits = l.numer().bits().max(l.denom().bits());
+            let exp = exp
+                .to_i32()
+                .filter(|exp| {
+                    base_bits.saturating_mul(exp.unsigned_abs().into()) <= MAX_LITERAL_BITS
+                })
+                .ok_or(EE::IntTooBig)?;
+            l.pow(exp)
+        }
+        Shl => {
+            let (l, r) = integers()?;
+            match shift_amount(&r)? {
+                _ if l.is_zero() => BigRational::zero(),
+                Some(n) => BigRational::from_integer(l << n),
+                None => return Err(EE::IntTooBig),
             }
-            hir::BinOpKind::Div => Self::new(l.data.checked_div(r.data).ok_or(EE::DivisionByZero)?),
-            hir::BinOpKind::Rem => Self::new(l.data.checked_rem(r.data).ok_or(EE::DivisionByZero)?),
-        })
+        }
+        // Rounds towards negative infinity.
+        Shr | Sar => {
+            let (l, r) = integers()?;
+            BigRational::from_integer(match shift_amount(&r)? {
+                Some(n) => l >> n,
+                None if l.is_negative() => BigInt::from(-1),
+                None => BigInt::zero(),
+            })
+        }
+        BitAnd | BitOr | BitXor => {
+            let (l, r) = integers()?;
+            BigRational::from_integer(match op {
+                BitAnd => l & r,
+                BitOr => l | r,
+                BitXor => l ^ r,
+                _ => unreachable!(),
+            })
+        }
+        Lt | Le | Gt | Ge | Eq | Ne | Or | And => return Err(EE::UnsupportedExpr),
+    };
+    if value.numer().bits() > MAX_LITERAL_BITS || value.denom().bits() > MAX_LITERAL_BITS {
+        return Err(EE::IntTooBig);
     }
+    Ok(value)
 }
 
 #[derive(Debug)]
@@ -210,11 +853,13 @@ pub enum EvalErrorKind {
     RecursionLimitReached,
     ArithmeticOverflow,
     IntTooBig,
+    BytesTooLong,
     DivisionByZero,
-    UnsupportedLiteral,
     UnsupportedUnaryOp,
     UnsupportedExpr,
     NonConstantVar,
+    NonIntValue,
+    NotAnInteger,
     AlreadyEmitted(ErrorGuaranteed),
 }
 use EvalErrorKind as EE;
@@ -229,11 +874,13 @@ impl EvalErrorKind {
             Self::RecursionLimitReached => "recursion limit reached",
             Self::ArithmeticOverflow => "arithmetic overflow",
             Self::IntTooBig => "integer value is too big",
+            Self::BytesTooLong => "byte string is too long for the fixed bytes type",
             Self::DivisionByZero => "division by zero",
-            Self::UnsupportedLiteral => "unsupported literal",
             Self::UnsupportedUnaryOp => "unsupported unary operation",
             Self::UnsupportedExpr => "unsupported expression",
             Self::NonConstantVar => "only constant variables are allowed",
+            Self::NonIntValue => "expected an integer value",
+            Self::NotAnInteger => "value is not an integer",
             Self::AlreadyEmitted(_) => "error already emitted",
         }
     }
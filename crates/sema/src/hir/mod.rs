@@ -12,7 +12,7 @@ use strum::EnumIs;
 
 pub use ast::{
     BinOp, BinOpKind, ContractKind, DataLocation, ElementaryType, FunctionKind, Lit,
-    StateMutability, UnOp, UnOpKind, VarMut, Visibility,
+    StateMutability, UnOp, UnOpKind, UserDefinableOperator, VarMut, Visibility,
 };
 
 mod visit;
@@ -297,6 +297,8 @@ pub struct Source<'hir> {
     pub imports: &'hir [(ast::ItemId, SourceId)],
     /// The source items.
     pub items: &'hir [ItemId],
+    /// The file-level `using for` directives.
+    pub using_directives: &'hir [UsingDirective<'hir>],
 }
 
 impl fmt::Debug for Source<'_> {
@@ -305,6 +307,7 @@ impl fmt::Debug for Source<'_> {
             .field("file", &self.file.name)
             .field("imports", &self.imports)
             .field("items", &self.items)
+            .field("using_directives", &self.using_directives)
             .finish()
     }
 }
@@ -522,6 +525,8 @@ pub struct Contract<'hir> {
     pub kind: ContractKind,
     /// The contract bases, as declared in the source code.
     pub bases: &'hir [ContractId],
+    /// The base constructor calls with arguments in the inheritance list: `is A(1)`.
+    pub base_args: &'hir [Modifier<'hir>],
     /// The linearized contract bases.
     ///
     /// The first element is the contract itself, followed by its bases in order of inheritance.
@@ -537,6 +542,10 @@ pub struct Contract<'hir> {
     /// Note that this only includes items defined in the contract itself, not inherited items.
     /// For getting all items, use [`Hir::contract_items`].
     pub items: &'hir [ItemId],
+    /// The `using for` directives declared in the contract.
+    ///
+    /// These are not inherited by derived contracts.
+    pub using_directives: &'hir [UsingDirective<'hir>],
 }
 
 impl Contract<'_> {
@@ -574,6 +583,52 @@ impl Contract<'_> {
     }
 }
 
+/// A modifier invocation or a base constructor call: `m(1)`, `Base(x)`.
+#[derive(Debug)]
+pub struct Modifier<'hir> {
+    /// The invocation span.
+    pub span: Span,
+    /// The invoked modifier function, or the base contract whose constructor is called.
+    pub id: ItemId,
+    /// The invocation arguments.
+    pub args: CallArgs<'hir>,
+}
+
+/// A `using for` directive: `using L for T;`, `using { f, add as + } for T global;`.
+#[derive(Debug)]
+pub struct UsingDirective<'hir> {
+    /// The directive span.
+    pub span: Span,
+    /// The attached functions.
+    pub list: UsingList<'hir>,
+    /// The type the functions are attached to. This is `*` if the value is `None`.
+    pub ty: Option<Type<'hir>>,
+    /// Whether the directive is marked `global`.
+    pub global: bool,
+}
+
+/// The functions attached by a [`UsingDirective`].
+#[derive(Clone, Copy, Debug)]
+pub enum UsingList<'hir> {
+    /// `using L for T;`: the functions of the library `L`.
+    Library(ContractId),
+    /// `using { f, L.g, add as + } for T;`
+    Functions(&'hir [UsingFunction]),
+}
+
+/// A function listed in a [`UsingDirective`].
+#[derive(Clone, Copy, Debug)]
+pub struct UsingFunction {
+    /// The path span.
+    pub span: Span,
+    /// The attached function.
+    pub id: FunctionId,
+    /// The user-defined operator this function implements, if any.
+    ///
+    /// Functions bound to an operator are not attached as members.
+    pub operator: Option<UserDefinableOperator>,
+}
+
 /// A function.
 #[derive(Debug)]
 pub struct Function<'hir> {
@@ -592,14 +647,15 @@ pub struct Function<'hir> {
     pub visibility: Visibility,
     /// The state mutability of the function.
     pub state_mutability: StateMutability,
-    /// Modifiers, or base classes if this is a constructor.
-    pub modifiers: &'hir [ItemId],
+    /// Modifier invocations, or base constructor calls if this is a constructor.
+    pub modifiers: &'hir [Modifier<'hir>],
     /// Whether this function is marked with the `virtual` keyword.
     pub marked_virtual: bool,
     /// Whether this function is marked with the `virtual` keyword or is defined in an interface.
     pub virtual_: bool,
     /// Whether this function is marked with the `override` keyword.
     pub override_: bool,
+    /// The contracts listed in the `override` specifier, if any.
     pub overrides: &'hir [ContractId],
     /// The function parameters.
     pub parameters: &'hir [VariableId],
@@ -1009,6 +1065,10 @@ pub struct StmtTry<'hir> {
 /// Reference: <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.catchClause>
 #[derive(Debug)]
 pub struct TryCatchClause<'hir> {
+    /// The span of the clause header, excluding the block: `returns (...)` or `catch Name(...)`.
+    ///
+    /// This is empty if the successful case has no `returns`.
+    pub span: Span,
     pub name: Option<Ident>,
     pub args: &'hir [VariableId],
     pub block: Block<'hir>,
@@ -1018,7 +1078,11 @@ pub struct TryCatchClause<'hir> {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LoopSource {
     /// A `for (...) { ... }` loop.
-    For,
+    For {
+        /// Whether the loop has a `next` expression, lowered as the last statement of the body
+        /// block.
+        has_next: bool,
+    },
     /// A `while (...) { ... }` loop.
     While,
     /// A `do { ... } while (...);` loop.
@@ -1029,7 +1093,7 @@ impl LoopSource {
     /// Returns the name of the loop source.
     pub fn name(self) -> &'static str {
         match self {
-            Self::For => "for",
+            Self::For { .. } => "for",
             Self::While => "while",
             Self::DoWhile => "do while",
         }
@@ -48,7 +48,9 @@ pub trait Visit<'hir> {
     }
 
     fn visit_contract(&mut self, contract: &'hir Contract<'hir>) -> ControlFlow<Self::BreakValue> {
-        // TODO: base initializers
+        for modifier in contract.base_args {
+            self.visit_modifier(modifier)?;
+        }
         visit_nested_items(self, contract.items)
     }
 
@@ -57,7 +59,9 @@ pub trait Visit<'hir> {
     }
 
     fn visit_function(&mut self, func: &'hir Function<'hir>) -> ControlFlow<Self::BreakValue> {
-        // TODO: modifiers
+        for modifier in func.modifiers {
+            self.visit_modifier(modifier)?;
+        }
         if let Some(body) = func.body {
             for stmt in body {
                 self.visit_stmt(stmt)?;
@@ -66,6 +70,13 @@ pub trait Visit<'hir> {
         ControlFlow::Continue(())
     }
 
+    fn visit_modifier(&mut self, modifier: &'hir Modifier<'hir>) -> ControlFlow<Self::BreakValue> {
+        for arg in modifier.args.exprs() {
+            self.visit_expr(arg)?;
+        }
+        ControlFlow::Continue(())
+    }
+
     fn visit_nested_var(&mut self, id: VariableId) -> ControlFlow<Self::BreakValue> {
         self.visit_var(self.hir().variable(id))
     }
@@ -80,8 +91,16 @@ pub trait Visit<'hir> {
 
     fn visit_expr(&mut self, expr: &'hir Expr<'hir>) -> ControlFlow<Self::BreakValue> {
         match expr.kind {
-            ExprKind::Call(expr, _, _)
-            | ExprKind::Delete(expr)
+            ExprKind::Call(callee, ref args, options) => {
+                self.visit_expr(callee)?;
+                for arg in args.exprs() {
+                    self.visit_expr(arg)?;
+                }
+                for option in options.unwrap_or_default() {
+                    self.visit_expr(&option.value)?;
+                }
+            }
+            ExprKind::Delete(expr)
             | ExprKind::Member(expr, _)
             | ExprKind::Payable(expr)
             | ExprKind::Unary(_, expr) => self.visit_expr(expr)?,
@@ -215,11 +215,13 @@ impl<'gcx, W: fmt::Write> TyAbiPrinter<'gcx, W> {
             }
 
             TyKind::StringLiteral(..)
-            | TyKind::IntLiteral(_)
+            | TyKind::IntLiteral(..)
+            | TyKind::RationalLiteral(_)
             | TyKind::Tuple(_)
             | TyKind::Mapping(..)
             | TyKind::Error(..)
             | TyKind::Event(..)
+            | TyKind::Super(_)
             | TyKind::Module(_)
             | TyKind::BuiltinModule(_)
             | TyKind::Type(_)
@@ -243,29 +245,29 @@ impl<'gcx, W: fmt::Write> TyAbiPrinter<'gcx, W> {
 
 /// Prints types as implemented in `Type::toString(bool)` in solc.
 ///
-/// This is mainly used in the `internalType` field of the ABI.
+/// This is used in the `internalType` field of the ABI, and in diagnostics through [`Ty::display`].
 ///
 /// Example: https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/ast/Types.cpp#L2352-L2358
-struct TySolcPrinter<'gcx, W> {
+pub(super) struct TySolcPrinter<'gcx, W> {
     gcx: Gcx<'gcx>,
     buf: W,
     data_locations: bool,
 }
 
 impl<'gcx, W: fmt::Write> TySolcPrinter<'gcx, W> {
-    fn new(gcx: Gcx<'gcx>, buf: W) -> Self {
+    pub(super) fn new(gcx: Gcx<'gcx>, buf: W) -> Self {
         Self { gcx, buf, data_locations: false }
     }
 
     /// Whether to print data locations for reference types.
     ///
     /// Default: `false`.
-    fn data_locations(mut self, yes: bool) -> Self {
+    pub(super) fn data_locations(mut self, yes: bool) -> Self {
         self.data_locations = yes;
         self
     }
 
-    fn print(&mut self, ty: Ty<'gcx>) -> fmt::Result {
+    pub(super) fn print(&mut self, ty: Ty<'gcx>) -> fmt::Result {
         match ty.kind {
             TyKind::Elementary(ty) => {
                 ty.write_abi_str(&mut self.buf)?;
@@ -274,17 +276,39 @@ impl<'gcx, W: fmt::Write> TySolcPrinter<'gcx, W> {
                 }
                 Ok(())
             }
+            TyKind::StringLiteral(..) => self.buf.write_str("literal_string"),
+            TyKind::IntLiteral(..) => self.buf.write_str("int_const"),
+            TyKind::RationalLiteral(_) => self.buf.write_str("rational_const"),
             TyKind::Contract(id) => {
                 let c = self.gcx.hir.contract(id);
                 self.buf.write_str(if c.kind.is_library() { "library" } else { "contract" })?;
                 write!(self.buf, " {}", c.name)
             }
-            TyKind::FnPtr(_) => self.buf.write_str("function"),
+            TyKind::Super(id) => {
+                write!(self.buf, "contract super {}", self.gcx.hir.contract(id).name)
+            }
+            TyKind::FnPtr(f) => {
+                self.buf.write_str("function ")?;
+                self.print_tuple(f.parameters)?;
+                if f.state_mutability != hir::StateMutability::NonPayable {
+                    write!(self.buf, " {}", f.state_mutability)?;
+                }
+                if f.is_external() {
+                    self.buf.write_str(" external")?;
+                }
+                if !f.returns.is_empty() {
+                    self.buf.write_str(" returns ")?;
+                    self.print_tuple(f.returns)?;
+                }
+                Ok(())
+            }
             TyKind::Struct(id) => {
                 write!(self.buf, "struct {}", self.gcx.item_canonical_name(id))
             }
             TyKind::Enum(id) => write!(self.buf, "enum {}", self.gcx.item_canonical_name(id)),
             TyKind::Udvt(_, id) => write!(self.buf, "{}", self.gcx.item_canonical_name(id)),
+            TyKind::Error(_, id) => write!(self.buf, "error {}", self.gcx.item_canonical_name(id)),
+            TyKind::Event(_, id) => write!(self.buf, "event {}", self.gcx.item_canonical_name(id)),
             TyKind::Ref(ty, loc) => {
                 self.print(ty)?;
                 if self.data_locations {
@@ -300,18 +324,38 @@ impl<'gcx, W: fmt::Write> TySolcPrinter<'gcx, W> {
                 self.print(ty)?;
                 write!(self.buf, "[{len}]")
             }
+            TyKind::Tuple(tys) => {
+                self.buf.write_str("tuple")?;
+                self.print_tuple(tys)
+            }
+            TyKind::Mapping(key, value) => {
+                self.buf.write_str("mapping(")?;
+                self.print(key)?;
+                self.buf.write_str(" => ")?;
+                self.print(value)?;
+                self.buf.write_str(")")
+            }
+            TyKind::Module(id) => {
+                write!(self.buf, "module \"{}\"", self.gcx.hir.source(id).file.name.display())
+            }
+            TyKind::BuiltinModule(builtin) => write!(self.buf, "{}", builtin.name()),
+            TyKind::Type(ty) | TyKind::Meta(ty) => {
+                self.buf.write_str("type(")?;
+                self.print(ty)?;
+                self.buf.write_str(")")
+            }
+            TyKind::Err(_) => self.buf.write_str("<error>"),
+        }
+    }
 
-            TyKind::StringLiteral(..)
-            | TyKind::IntLiteral(_)
-            | TyKind::Tuple(_)
-            | TyKind::Mapping(..)
-            | TyKind::Error(..)
-            | TyKind::Event(..)
-            | TyKind::Module(_)
-            | TyKind::BuiltinModule(_)
-            | TyKind::Type(_)
-            | TyKind::Meta(_)
-            | TyKind::Err(_) => panic!("printing unsupported type as solc: {ty:?}"),
+    fn print_tuple(&mut self, tys: &[Ty<'gcx>]) -> fmt::Result {
+        self.buf.write_str("(")?;
+        for (i, &ty) in tys.iter().enumerate() {
+            if i > 0 {
+                self.buf.write_str(",")?;
+            }
+            self.print(ty)?;
         }
+        self.buf.write_str(")")
     }
 }
@@ -0,0 +1,163 @@
+//! Implicit and explicit type conversions.
+//!
+//! Reference: <https://docs.soliditylang.org/en/latest/types.html#conversions-between-elementary-types>
+
+use super::{Gcx, Ty, TyKind};
+use crate::hir;
+use solar_ast::{DataLocation, ElementaryType, StateMutability};
+
+impl<'gcx> Ty<'gcx> {
+    /// Returns `true` if `self` can be implicitly converted to `to`.
+    ///
+    /// Types that contain errors are convertible to and from every other type.
+    ///
+    /// Reference: <https://docs.soliditylang.org/en/latest/types.html#implicit-conversions>
+    pub fn is_implicitly_convertible_to(self, gcx: Gcx<'gcx>, to: Self) -> bool {
+        use ElementaryType::*;
+
+        if self == to || self.has_error().is_err() || to.has_error().is_err() {
+            return true;
+        }
+        // Anything can be copied to memory, but storage pointers can only point to storage, and
+        // calldata is read-only. Copies to storage are handled by the type checker.
+        if let TyKind::Ref(_, to_loc) = to.kind {
+            let from_loc = match self.kind {
+                TyKind::Ref(_, loc) => Some(loc),
+                _ => None,
+            };
+            let loc_ok = match to_loc {
+                DataLocation::Memory => true,
+                DataLocation::Storage | DataLocation::Transient => from_loc == Some(to_loc),
+                DataLocation::Calldata => from_loc == Some(DataLocation::Calldata),
+            };
+            if !loc_ok {
+                return false;
+            }
+        }
+        let (from, to) = (self.peel_refs(), to.peel_refs());
+        if from == to {
+            return true;
+        }
+        match (&from.kind, &to.kind) {
+            (&TyKind::IntLiteral(negative, bits), &TyKind::Elementary(UInt(size))) => {
+                !negative && bits <= size.bits()
+            }
+            (&TyKind::IntLiteral(_, bits), &TyKind::Elementary(Int(size))) => bits < size.bits(),
+            // Only zero. Hex literals are handled by the type checker, since their type does not
+            // contain the number of digits.
+            (&TyKind::IntLiteral(negative, bits), &TyKind::Elementary(FixedBytes(_))) => {
+                !negative && bits == 0
+            }
+
+            (&TyKind::StringLiteral(utf8, _), &TyKind::Elementary(String)) => utf8,
+            (&TyKind::StringLiteral(..), &TyKind::Elementary(Bytes)) => true,
+            (&TyKind::StringLiteral(_, len), &TyKind::Elementary(FixedBytes(size))) => {
+                len.bytes_raw() <= size.bytes()
+            }
+
+            (&TyKind::Elementary(UInt(from)), &TyKind::Elementary(UInt(to)))
+            | (&TyKind::Elementary(Int(from)), &TyKind::Elementary(Int(to)))
+            | (&TyKind::Elementary(FixedBytes(from)), &TyKind::Elementary(FixedBytes(to))) => {
+                from.bytes() <= to.bytes()
+            }
+            (&TyKind::Elementary(Address(true)), &TyKind::Elementary(Address(false))) => true,
+
+            (&TyKind::Contract(from), &TyKind::Contract(to)) => {
+                gcx.hir.contract(from).linearized_bases.contains(&to)
+            }
+
+            (&TyKind::FnPtr(from), &TyKind::FnPtr(to)) => {
+                let mutability_ok = from.state_mutability == to.state_mutability
+                    || match to.state_mutability {
+                        StateMutability::NonPayable => true,
+                        StateMutability::View => from.state_mutability == StateMutability::Pure,
+                        StateMutability::Pure | StateMutability::Payable => false,
+                    };
+                from.parameters == to.parameters
+                    && from.returns == to.returns
+                    && from.is_external() == to.is_external()
+                    && mutability_ok
+            }
+
+            // Empty components of the target tuple accept any type, as in `(, x) = f()`.
+            (&TyKind::Tuple(from), &TyKind::Tuple(to)) => {
+                from.len() == to.len()
+                    && std::iter::zip(from, to).all(|(&from, &to)| {
+                        to == gcx.types.unit || from.is_implicitly_convertible_to(gcx, to)
+                    })
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `self` can be explicitly converted to `to`, as in `T(x)`.
+    ///
+    /// Reference: <https://docs.soliditylang.org/en/latest/types.html#explicit-conversions>
+    pub fn is_explicitly_convertible_to(self, gcx: Gcx<'gcx>, to: Self) -> bool {
+        use ElementaryType::*;
+
+        if self.is_implicitly_convertible_to(gcx, to) {
+            return true;
+        }
+        let (from, to) = (self.peel_refs(), to.peel_refs());
+        match (&from.kind, &to.kind) {
+            (&TyKind::IntLiteral(negative, bits), &TyKind::Elementary(Address(payable))) => {
+                !negative && (bits == 0 || (!payable && bits <= 160))
+            }
+            (&TyKind::IntLiteral(negative, _), &TyKind::Enum(_)) => !negative,
+
+            // Either the size or the sign can change, but not both at the same time.
+            (
+                &TyKind::Elementary(from @ (Int(_) | UInt(_))),
+                &TyKind::Elementary(to @ (Int(_) | UInt(_))),
+            ) => {
+                let (Int(from_size) | UInt(from_size)) = from else { unreachable!() };
+                let (Int(to_size) | UInt(to_size)) = to else { unreachable!() };
+                from_size == to_size || matches!(from, Int(_)) == matches!(to, Int(_))
+            }
+            (&TyKind::Elementary(UInt(size)), &TyKind::Elementary(Address(false))) => {
+                size.bits() == 160
+            }
+            (&TyKind::Elementary(UInt(from)), &TyKind::Elementary(FixedBytes(to)))
+            | (&TyKind::Elementary(FixedBytes(to)), &TyKind::Elementary(UInt(from))) => {
+                from.bits() == to.bits()
+            }
+            (&TyKind::Elementary(Int(_) | UInt(_)), &TyKind::Enum(_))
+            | (&TyKind::Enum(_), &TyKind::Elementary(Int(_) | UInt(_))) => true,
+
+            (&TyKind::Elementary(FixedBytes(_)), &TyKind::Elementary(FixedBytes(_))) => true,
+            (&TyKind::Elementary(FixedBytes(size)), &TyKind::Elementary(Address(_))) => {
+                size.bytes() == 20
+            }
+
+            (&TyKind::Elementary(Address(_)), &TyKind::Elementary(Address(_))) => true,
+            (&TyKind::Elementary(Address(false)), &TyKind::Elementary(UInt(size))) => {
+                size.bits() == 160
+            }
+            (&TyKind::Elementary(Address(false)), &TyKind::Elementary(FixedBytes(size))) => {
+                size.bytes() == 20
+            }
+            (&TyKind::Elementary(Address(payable)), &TyKind::Contract(id)) => {
+                payable || !is_payable_contract(gcx, id)
+            }
+            (&TyKind::Contract(id), &TyKind::Elementary(Address(payable))) => {
+                !payable || is_payable_contract(gcx, id)
+            }
+
+            (&TyKind::Elementary(Bytes), &TyKind::Elementary(FixedBytes(_))) => true,
+            (&TyKind::Elementary(Bytes | String), &TyKind::Elementary(Bytes | String)) => true,
+
+            _ => false,
+        }
+    }
+}
+
+/// Returns `true` if the contract can receive Ether, that is if it has a `receive` function or a
+/// payable `fallback` function.
+fn is_payable_contract(gcx: Gcx<'_>, id: hir::ContractId) -> bool {
+    let c = gcx.hir.contract(id);
+    c.receive.is_some()
+        || c.fallback
+            .is_some_and(|f| gcx.hir.function(f).state_mutability == StateMutability::Payable)
+}
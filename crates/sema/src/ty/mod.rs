@@ -5,7 +5,7 @@ use crate::{
 };
 use alloy_primitives::{keccak256, Selector, B256, U256};
 use either::Either;
-use solar_ast::{DataLocation, StateMutability, TypeSize, Visibility};
+use solar_ast::{DataLocation, ElementaryType, StateMutability, TypeSize, Visibility};
 use solar_data_structures::{
     fmt::{from_fn, or_list},
     map::{FxBuildHasher, FxHashMap, FxHashSet},
@@ -29,6 +29,8 @@ pub use abi::{TyAbiPrinter, TyAbiPrinterMode};
 mod common;
 pub use common::{CommonTypes, EachDataLoc};
 
+mod convert;
+
 mod interner;
 use interner::Interner;
 
@@ -148,10 +150,23 @@ pub struct GlobalCtxt<'gcx> {
     pub hir: Hir<'gcx>,
     pub(crate) symbol_resolver: SymbolResolver<'gcx>,
 
+    pub(crate) typeck_results: TypeckResults<'gcx>,
+
     interner: Interner<'gcx>,
     cache: Cache<'gcx>,
 }
 
+/// The results of type checking, populated by `typeck`.
+#[derive(Default)]
+pub(crate) struct TypeckResults<'gcx> {
+    /// The type of each checked expression.
+    pub(crate) expr_types: scc::HashMap<hir::ExprId, Ty<'gcx>, FxBuildHasher>,
+    /// The resolution of each identifier expression, after overload resolution.
+    pub(crate) expr_resolutions: scc::HashMap<hir::ExprId, hir::Res, FxBuildHasher>,
+    /// The resolved member of each member access expression.
+    pub(crate) expr_members: scc::HashMap<hir::ExprId, members::Member<'gcx>, FxBuildHasher>,
+}
+
 impl<'gcx> GlobalCtxt<'gcx> {
     pub(crate) fn new(
         sess: &'gcx Session,
@@ -165,6 +180,7 @@ impl<'gcx> GlobalCtxt<'gcx> {
             types: CommonTypes::new(&interner),
             hir,
             symbol_resolver,
+            typeck_results: TypeckResults::default(),
             interner,
             cache: Cache::default(),
         }
@@ -216,8 +232,8 @@ impl<'gcx> Gcx<'gcx> {
         ))
     }
 
-    pub fn mk_ty_int_literal(self, size: TypeSize) -> Ty<'gcx> {
-        self.mk_ty(TyKind::IntLiteral(size))
+    pub fn mk_ty_int_literal(self, negative: bool, bits: u16) -> Ty<'gcx> {
+        self.mk_ty(TyKind::IntLiteral(negative, bits))
     }
 
     pub fn mk_ty_fn_ptr(self, ptr: TyFnPtr<'gcx>) -> Ty<'gcx> {
@@ -393,7 +409,16 @@ impl<'gcx> Gcx<'gcx> {
     /// Computes the [`Ty`] of the given [`hir::Type`]. Not cached.
     pub fn type_of_hir_ty(self, ty: &hir::Type<'_>) -> Ty<'gcx> {
         let kind = match ty.kind {
-            hir::TypeKind::Elementary(ty) => TyKind::Elementary(ty),
+            hir::TypeKind::Elementary(ty) => TyKind::Elementary(match ty {
+                // Normalize `int` and `uint` to their explicitly-sized equivalents.
+                ElementaryType::Int(size) if size == TypeSize::ZERO => {
+                    ElementaryType::Int(TypeSize::new_int_bits(256))
+                }
+                ElementaryType::UInt(size) if size == TypeSize::ZERO => {
+                    ElementaryType::UInt(TypeSize::new_int_bits(256))
+                }
+                ty => ty,
+            }),
             hir::TypeKind::Array(array) => {
                 let ty = self.type_of_hir_ty(&array.element);
                 match array.size {
@@ -442,9 +467,49 @@ impl<'gcx> Gcx<'gcx> {
         }
     }
 
+    /// Returns the type of the given expression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression has not been type checked.
+    pub fn type_of_expr(self, id: hir::ExprId) -> Ty<'gcx> {
+        self.type_of_expr_opt(id)
+            .unwrap_or_else(|| panic!("type_of_expr: missing type for expression {id:?}"))
+    }
+
+    /// Returns the type of the given expression, if it has been type checked.
+    pub fn type_of_expr_opt(self, id: hir::ExprId) -> Option<Ty<'gcx>> {
+        self.typeck_results.expr_types.read(&id, |_, &ty| ty)
+    }
+
+    /// Returns the resolution of the given identifier expression.
+    ///
+    /// If the identifier refers to multiple overloaded declarations, this is the one selected by
+    /// overload resolution. Returns `None` if the expression is not an identifier, has not been type
+    /// checked, or could not be resolved.
+    pub fn res_of_expr(self, id: hir::ExprId) -> Option<hir::Res> {
+        self.typeck_results.expr_resolutions.read(&id, |_, &res| res)
+    }
+
+    /// Returns the resolved member of the given member access expression.
+    ///
+    /// Returns `None` if the expression is not a member access, has not been type checked, or
+    /// could not be resolved.
+    pub fn member_of_expr(self, id: hir::ExprId) -> Option<members::Member<'gcx>> {
+        self.typeck_results.expr_members.read(&id, |_, &member| member)
+    }
+
     /// Returns the type of the given [`hir::Res`].
+    ///
+    /// Type declarations have type [`TyKind::Type`], as in `Struct` or `Enum` in `Enum.Variant`.
     pub fn type_of_res(self, res: hir::Res) -> Ty<'gcx> {
         match res {
+            hir::Res::Item(
+                id @ (hir::ItemId::Contract(_)
+                | hir::ItemId::Struct(_)
+                | hir::ItemId::Enum(_)
+                | hir::ItemId::Udvt(_)),
+            ) => self.type_of_item(id).make_type_type(self),
             hir::Res::Item(id) => self.type_of_item(id),
             hir::Res::Namespace(id) => self.mk_ty(TyKind::Module(id)),
             hir::Res::Builtin(builtin) => builtin.ty(self),
@@ -633,6 +698,19 @@ pub fn type_of_item(gcx: _, id: hir::ItemId) -> Ty<'gcx> {
     gcx.mk_ty(kind)
 }
 
+/// Returns the value of the given constant variable.
+///
+/// Returns `None` if the variable is not a constant, or if its initializer cannot be evaluated by
+/// the [`ConstantEvaluator`](crate::eval::ConstantEvaluator). Negative values are returned in two's
+/// complement representation.
+pub fn constant_value(gcx: _, id: hir::VariableId) -> Option<crate::eval::ConstValue<'gcx>> {
+    let var = gcx.hir.variable(id);
+    if !var.is_constant() || var.initializer.is_none() {
+        return None;
+    }
+    crate::eval::ConstantEvaluator::new(gcx).try_eval_variable(id).ok()
+}
+
 /// Returns the types of the fields of the given struct.
 pub fn struct_field_types(gcx: _, id: hir::StructId) -> &'gcx [Ty<'gcx>] {
     gcx.mk_ty_iter(gcx.hir.strukt(id).fields.iter().map(|&f| gcx.type_of_item(f.into())))
@@ -694,6 +772,23 @@ pub fn struct_recursiveness(gcx: _, id: hir::StructId) -> Recursiveness {
 pub fn members_of(gcx: _, ty: Ty<'gcx>) -> members::MemberList<'gcx> {
     members::members_of(gcx, ty)
 }
+
+/// Returns the functions attached by the `using for` directives that are visible in the given
+/// source and contract, along with the type they are attached to, or `None` for `*`.
+pub(crate) fn using_for_table(gcx: _, scope: (hir::SourceId, Option<hir::ContractId>)) -> &'gcx [(Option<Ty<'gcx>>, hir::FunctionId)] {
+    crate::typeck::using_for::using_for_table(gcx, scope.0, scope.1)
+}
+
+/// Returns the functions attached to the given type by the `using for` directives that are
+/// visible in the given source and contract.
+pub(crate) fn attached_functions(gcx: _, key: (hir::SourceId, Option<hir::ContractId>, Ty<'gcx>)) -> members::MemberList<'gcx> {
+    crate::typeck::using_for::attached_functions(gcx, key.0, key.1, key.2)
+}
+
+/// Returns the user-defined operators of the given user-defined value type.
+pub(crate) fn udvt_operators(gcx: _, id: hir::UdvtId) -> &'gcx [(hir::UserDefinableOperator, hir::FunctionId)] {
+    crate::typeck::using_for::udvt_operators(gcx, id)
+}
 }
 
 fn var_type<'gcx>(gcx: Gcx<'gcx>, var: &'gcx hir::Variable<'gcx>, ty: Ty<'gcx>) -> Ty<'gcx> {
@@ -805,6 +900,25 @@ fn var_type<'gcx>(gcx: Gcx<'gcx>, var: &'gcx hir::Variable<'gcx>, ty: Ty<'gcx>)
         }
     };
 
+    // Mappings in public function signatures are reported in `interface_functions`.
+    let in_public_signature = var.function.is_some_and(|f| {
+        let f = gcx.hir.function(f);
+        f.visibility >= hir::Visibility::Public
+            && !f.contract.is_some_and(|c| gcx.hir.contract(c).kind.is_library())
+    });
+    if ty.has_mapping()
+        && matches!(var.data_location, Some(Memory | Calldata))
+        && var_loc == var.data_location
+        && !in_public_signature
+        && ty.has_error().is_ok()
+    {
+        let msg = format!(
+            "type `{}` is only valid in storage because it contains a (nested) mapping",
+            ty.display(gcx)
+        );
+        gcx.dcx().err(msg).span(var.span).emit();
+    }
+
     if ty.is_reference_type() {
         ty.with_loc(gcx, ty_loc)
     } else {
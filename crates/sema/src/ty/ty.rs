@@ -1,8 +1,8 @@
-use super::{Gcx, Recursiveness};
+use super::{abi::TySolcPrinter, Gcx, Recursiveness};
 use crate::{builtins::Builtin, hir};
 use alloy_primitives::U256;
 use solar_ast::{DataLocation, ElementaryType, StateMutability, TypeSize, Visibility};
-use solar_data_structures::Interned;
+use solar_data_structures::{fmt::from_fn, Interned};
 use solar_interface::diagnostics::ErrorGuaranteed;
 use std::{borrow::Borrow, fmt, hash::Hash, ops::ControlFlow};
 
@@ -205,14 +205,23 @@ impl<'gcx> Ty<'gcx> {
         }
     }
 
+    /// Returns a displayable representation of the type, including data locations.
+    ///
+    /// This follows `Type::toString` in solc and is meant to be used in diagnostics.
+    pub fn display(self, gcx: Gcx<'gcx>) -> impl fmt::Display + use<'gcx> {
+        from_fn(move |f| TySolcPrinter::new(gcx, f).data_locations(true).print(self))
+    }
+
     /// Visits the type and its subtypes.
     pub fn visit<T>(self, f: &mut impl FnMut(Self) -> ControlFlow<T>) -> ControlFlow<T> {
         f(self)?;
         match self.kind {
             TyKind::Elementary(_)
             | TyKind::StringLiteral(..)
-            | TyKind::IntLiteral(_)
+            | TyKind::IntLiteral(..)
+            | TyKind::RationalLiteral(_)
             | TyKind::Contract(_)
+            | TyKind::Super(_)
             | TyKind::FnPtr(_)
             | TyKind::Enum(_)
             | TyKind::Module(_)
@@ -271,8 +280,16 @@ pub enum TyKind<'gcx> {
     /// - only string literals with `len <= N` can coerce to `bytesN`
     StringLiteral(bool, TypeSize),
 
-    /// Any integer or fixed-point number literal. Contains `min(s.len(), 32)`.
-    IntLiteral(TypeSize),
+    /// Any integer literal, or a constant expression of literals that evaluates to an integer.
+    /// Contains `(negative, bits)`, where `bits` is the number of bits needed to store the
+    /// absolute value of the number, minus one if it is negative.
+    /// - non-negative integer literals can coerce to `uintN` if `bits <= N`
+    /// - all integer literals can coerce to `intN` if `bits < N`
+    IntLiteral(bool, u16),
+
+    /// Any fractional number literal, or a constant expression of literals that does not evaluate
+    /// to an integer. Contains whether the number is negative.
+    RationalLiteral(bool),
 
     /// A reference to another type which lives in the data location.
     Ref(Ty<'gcx>, DataLocation),
@@ -295,6 +312,12 @@ pub enum TyKind<'gcx> {
     /// Contract.
     Contract(hir::ContractId),
 
+    /// The type of `super` in the given contract.
+    ///
+    /// Its members are the functions of the contract's bases, looked up starting at the next
+    /// contract in its linearization.
+    Super(hir::ContractId),
+
     /// A struct.
     ///
     /// Cannot contain the types of its fields because it can be recursive.
@@ -342,6 +365,11 @@ impl<'gcx> TyFnPtr<'gcx> {
     pub fn tys(&self) -> impl DoubleEndedIterator<Item = Ty<'gcx>> + Clone {
         self.parameters.iter().copied().chain(self.returns.iter().copied())
     }
+
+    /// Returns `true` if this is an external function type, i.e. it can be called externally.
+    pub fn is_external(&self) -> bool {
+        matches!(self.visibility, Visibility::Public | Visibility::External)
+    }
 }
 
 bitflags::bitflags! {
@@ -368,8 +396,10 @@ impl TyFlags {
         match *ty {
             TyKind::Elementary(_)
             | TyKind::StringLiteral(..)
-            | TyKind::IntLiteral(_)
+            | TyKind::IntLiteral(..)
+            | TyKind::RationalLiteral(_)
             | TyKind::Contract(_)
+            | TyKind::Super(_)
             | TyKind::FnPtr(_)
             | TyKind::Enum(_)
             | TyKind::Module(_)
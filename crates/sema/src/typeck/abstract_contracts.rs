@@ -0,0 +1,80 @@
+//! Abstract contract and interface checks.
+//!
+//! Reference: <https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/analysis/ContractLevelChecker.cpp>
+
+use super::overrides::{fn_span, signature};
+use crate::{
+    hir::{self, ContractKind, FunctionId},
+    ty::Gcx,
+};
+use solar_ast::Visibility;
+use solar_data_structures::map::FxHashSet;
+
+pub(super) fn check_contract(gcx: Gcx<'_>, id: hir::ContractId) {
+    let c = gcx.hir.contract(id);
+    match c.kind {
+        ContractKind::Contract => check_unimplemented_functions(gcx, c),
+        ContractKind::Interface => check_interface(gcx, c),
+        ContractKind::AbstractContract | ContractKind::Library => {}
+    }
+}
+
+/// Checks that a non-abstract contract implements all the functions and modifiers it declares
+/// or inherits.
+fn check_unimplemented_functions(gcx: Gcx<'_>, c: &hir::Contract<'_>) {
+    // The first function found in the linearization is the most derived one.
+    let mut seen = FxHashSet::default();
+    let mut missing = Vec::<FunctionId>::new();
+    for &base in c.linearized_bases {
+        for f_id in gcx.hir.contract(base).functions() {
+            if !seen.insert(signature(gcx, f_id)) {
+                continue;
+            }
+            let f = gcx.hir.function(f_id);
+            if f.body.is_none() && !f.is_getter() {
+                missing.push(f_id);
+            }
+        }
+    }
+    if missing.is_empty() {
+        return;
+    }
+
+    let msg = format!("contract `{}` should be marked as abstract", c.name);
+    let mut diag = gcx.dcx().err(msg).span(c.name.span);
+    for f_id in missing {
+        let f = gcx.hir.function(f_id);
+        let what = if f.kind.is_modifier() { "modifier" } else { "function" };
+        let name = f.name.map_or_else(|| f.kind.to_str().to_string(), |name| name.to_string());
+        let base = gcx.hir.contract(f.contract.unwrap()).name;
+        let note = format!("missing implementation of {what} `{name}` declared in `{base}`");
+        diag = diag.span_note(fn_span(gcx, f_id), note);
+    }
+    diag.emit();
+}
+
+/// Checks the items declared in an interface.
+fn check_interface(gcx: Gcx<'_>, c: &hir::Contract<'_>) {
+    let dcx = gcx.dcx();
+    for var_id in c.variables() {
+        let msg = "variables cannot be declared in interfaces";
+        dcx.err(msg).span(gcx.hir.variable(var_id).span).emit();
+    }
+    if let Some(ctor) = c.ctor {
+        dcx.err("constructors cannot be defined in interfaces").span(fn_span(gcx, ctor)).emit();
+    }
+    for f_id in c.all_functions() {
+        let f = gcx.hir.function(f_id);
+        if f.kind.is_constructor() || f.kind.is_modifier() {
+            continue;
+        }
+        if f.visibility != Visibility::External {
+            let msg = "functions in interfaces must be declared `external`";
+            dcx.err(msg).span(fn_span(gcx, f_id)).emit();
+        }
+        if f.body.is_some() {
+            let msg = "functions in interfaces cannot have an implementation";
+            dcx.err(msg).span(fn_span(gcx, f_id)).emit();
+        }
+    }
+}
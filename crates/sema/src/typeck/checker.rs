@@ -0,0 +1,2159 @@
+//! Expression type checking.
+//!
+//! Computes the type of every expression in function bodies and variable initializers, and checks
+//! that they are used consistently.
+
+use super::using_for;
+use crate::{
+    builtins::{members, Builtin, Member},
+    eval::{fold_rationals, ConstantEvaluator},
+    hir::{self, BinOpKind, ExprKind, Res, StmtKind, UnOpKind},
+    ty::{Gcx, Ty, TyFnPtr, TyKind},
+};
+use alloy_primitives::U256;
+use either::Either;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use solar_ast::{DataLocation, ElementaryType, LitKind, StateMutability};
+use solar_data_structures::{map::FxHashMap, smallvec::SmallVec};
+use solar_interface::{
+    diagnostics::{DiagBuilder, ErrorGuaranteed},
+    kw, sym, Ident, Span, Symbol,
+};
+
+/// Type checks the body of the given function.
+pub(super) fn check_function(gcx: Gcx<'_>, id: hir::FunctionId) {
+    let f = gcx.hir.function(id);
+    // Getter bodies are generated by the compiler.
+    if f.is_getter() {
+        return;
+    }
+    let mut checker = TypeChecker::new(gcx, f.source, f.contract, Some(id));
+    for &var in f.parameters.iter().chain(f.returns) {
+        checker.check_hir_ty(&gcx.hir.variable(var).ty);
+    }
+    for modifier in f.modifiers {
+        checker.check_modifier(modifier);
+    }
+    let Some(body) = f.body else { return };
+    checker.check_block(body);
+}
+
+/// Type checks the base constructor arguments given in the inheritance list of the given
+/// contract, and checks that the arguments of each base constructor are given exactly once.
+pub(super) fn check_contract(gcx: Gcx<'_>, id: hir::ContractId) {
+    let contract = gcx.hir.contract(id);
+    let mut checker = TypeChecker::new(gcx, contract.source, Some(id), None);
+    for modifier in contract.base_args {
+        checker.check_modifier(modifier);
+    }
+    check_base_constructor_calls(gcx, id);
+}
+
+fn check_base_constructor_calls(gcx: Gcx<'_>, id: hir::ContractId) {
+    let contract = gcx.hir.contract(id);
+    let linearized_bases = |id: hir::ContractId| gcx.hir.contract(id).linearized_bases;
+
+    // Maps each base contract to the contract and call that provide its constructor arguments.
+    let mut calls = FxHashMap::<hir::ContractId, (hir::ContractId, Span)>::default();
+    for &caller in contract.linearized_bases {
+        let c = gcx.hir.contract(caller);
+        let ctor_calls = c.ctor.map(|ctor| gcx.hir.function(ctor).modifiers).unwrap_or_default();
+        for call in c.base_args.iter().chain(ctor_calls) {
+            let hir::ItemId::Contract(base) = call.id else { continue };
+            if call.args.is_empty() {
+                continue;
+            }
+            let Some(&(prev_caller, prev_span)) = calls.get(&base) else {
+                calls.insert(base, (caller, call.span));
+                continue;
+            };
+            // Report the error only in the first contract that inherits from both callers.
+            let reported_in_base = contract.linearized_bases[1..].iter().any(|&b| {
+                linearized_bases(b).contains(&caller) && linearized_bases(b).contains(&prev_caller)
+            });
+            if reported_in_base {
+                continue;
+            }
+            let mut diag = gcx.dcx().err("base constructor arguments given twice");
+            diag = if caller == id {
+                diag.span(call.span)
+            } else {
+                diag.span(contract.name.span)
+                    .span_note(call.span, "second constructor call is here")
+            };
+            diag.span_note(prev_span, "first constructor call is here").emit();
+        }
+    }
+
+    if contract.kind != hir::ContractKind::Contract {
+        return;
+    }
+    for &base in &contract.linearized_bases[1..] {
+        let Some(ctor) = gcx.hir.contract(base).ctor else { continue };
+        if gcx.hir.function(ctor).parameters.is_empty() || calls.contains_key(&base) {
+            continue;
+        }
+        let msg =
+            format!("no arguments passed to the base constructor of `{}`", gcx.item_name(base));
+        gcx.dcx()
+            .err(msg)
+            .span(contract.name.span)
+            .help(format!("specify the arguments or mark `{}` as abstract", contract.name))
+            .emit();
+    }
+}
+
+/// Type checks the declared type and the initializer of the given variable that is not declared
+/// in a function, such as state variables, struct fields, and event and error parameters.
+///
+/// Function parameters and local variables are checked as part of their function.
+pub(super) fn check_variable(gcx: Gcx<'_>, id: hir::VariableId) {
+    let var = gcx.hir.variable(id);
+    if var.function.is_some() {
+        return;
+    }
+    let mut checker = TypeChecker::new(gcx, var.source, var.contract, None);
+    checker.check_hir_ty(&var.ty);
+    let Some(init) = var.initializer else { return };
+    checker.check_expr_with(init, gcx.type_of_item(id.into()));
+}
+
+struct TypeChecker<'gcx> {
+    gcx: Gcx<'gcx>,
+    source: hir::SourceId,
+    contract: Option<hir::ContractId>,
+    function: Option<hir::FunctionId>,
+    /// The call expression that is allowed to invoke an event or an error: the expression of the
+    /// current `emit` or `revert` statement, or the error argument of `require`.
+    event_or_error_call: Option<hir::ExprId>,
+}
+
+impl<'gcx> TypeChecker<'gcx> {
+    fn new(
+        gcx: Gcx<'gcx>,
+        source: hir::SourceId,
+        contract: Option<hir::ContractId>,
+        function: Option<hir::FunctionId>,
+    ) -> Self {
+        Self { gcx, source, contract, function, event_or_error_call: None }
+    }
+
+    /// Type checks the arguments of a modifier invocation or a base constructor call.
+    fn check_modifier(&mut self, modifier: &hir::Modifier<'_>) {
+        let gcx = self.gcx;
+        let (id, descr) = match modifier.id {
+            hir::ItemId::Function(id) => (Some(id), "modifier"),
+            // Base constructors without arguments are called from elsewhere.
+            hir::ItemId::Contract(_) if modifier.args.is_empty() => return,
+            hir::ItemId::Contract(id) => (gcx.hir.contract(id).ctor, "base constructor"),
+            _ => unreachable!(),
+        };
+        let (parameters, names) = match id {
+            Some(id) => (gcx.item_parameter_types(id), Some(gcx.item_parameters(id))),
+            None => (&[][..], Some(&[][..])),
+        };
+        self.check_call_args(modifier.span, &modifier.args, parameters, names, descr);
+    }
+
+    fn check_block(&mut self, block: hir::Block<'_>) {
+        for stmt in block {
+            self.check_stmt(stmt);
+        }
+    }
+
+    fn check_stmt(&mut self, stmt: &hir::Stmt<'_>) {
+        match stmt.kind {
+            StmtKind::DeclSingle(id) => {
+                let var = self.gcx.hir.variable(id);
+                self.check_hir_ty(&var.ty);
+                let ty = self.gcx.type_of_item(id.into());
+                if let Some(init) = var.initializer {
+                    self.check_expr_with(init, ty);
+                } else if ty.is_ref_at(DataLocation::Storage) {
+                    self.gcx.dcx().err("uninitialized storage pointer").span(var.span).emit();
+                } else if let TyKind::Mapping(..) = ty.kind {
+                    self.gcx
+                        .dcx()
+                        .err("uninitialized mapping")
+                        .span(var.span)
+                        .help(
+                            "mappings cannot be created dynamically, \
+                             you have to assign them from a state variable",
+                        )
+                        .emit();
+                }
+            }
+            StmtKind::DeclMulti(vars, expr) => {
+                for &id in vars.iter().flatten() {
+                    self.check_hir_ty(&self.gcx.hir.variable(id).ty);
+                }
+                let ty = self.check_expr(expr);
+                self.check_decl_multi(vars, expr, ty);
+            }
+            StmtKind::Block(block) | StmtKind::UncheckedBlock(block) => self.check_block(block),
+            StmtKind::Emit(expr) => self.check_emit_or_revert(expr, true),
+            StmtKind::Revert(expr) => self.check_emit_or_revert(expr, false),
+            StmtKind::Expr(expr) => {
+                self.check_expr(expr);
+            }
+            StmtKind::Return(expr) => self.check_return(stmt, expr),
+            StmtKind::Loop(block, _) => self.check_block(block),
+            StmtKind::If(cond, then, else_) => {
+                self.check_expr_with(cond, self.gcx.types.bool);
+                self.check_stmt(then);
+                if let Some(else_) = else_ {
+                    self.check_stmt(else_);
+                }
+            }
+            StmtKind::Try(try_) => self.check_try(try_),
+            StmtKind::Break | StmtKind::Continue | StmtKind::Placeholder | StmtKind::Err(_) => {}
+        }
+    }
+
+    /// Checks that the call of an `emit` statement invokes an event, or that the call of a
+    /// `revert` statement invokes an error.
+    fn check_emit_or_revert(&mut self, expr: &hir::Expr<'_>, is_emit: bool) {
+        let gcx = self.gcx;
+        self.event_or_error_call = Some(expr.id);
+        self.check_expr(expr);
+        self.event_or_error_call = None;
+
+        let ExprKind::Call(callee, ..) = expr.kind else { unreachable!() };
+        let Some(callee_ty) = gcx.type_of_expr_opt(callee.id) else { return };
+        let msg = match callee_ty.kind {
+            TyKind::Event(..) if is_emit => return,
+            TyKind::Error(..) if !is_emit => return,
+            TyKind::Err(_) => return,
+            _ if is_emit => "expression has to be an event invocation",
+            _ => "expression has to be an error",
+        };
+        gcx.dcx().err(msg).span(callee.span).emit();
+    }
+
+    fn check_try(&mut self, try_: &hir::StmtTry<'_>) {
+        let gcx = self.gcx;
+        let ty = self.check_expr(&try_.expr);
+        for clause in try_.clauses {
+            for &id in clause.args {
+                self.check_hir_ty(&gcx.hir.variable(id).ty);
+            }
+        }
+
+        let (success, catches) = try_.clauses.split_first().unwrap();
+        if let Some(returns) = self.try_call_returns(&try_.expr, ty) {
+            // The success clause has no variables if `returns` was omitted.
+            if !success.args.is_empty() {
+                self.check_try_returns(success, returns);
+            }
+        }
+        self.check_catch_clauses(catches);
+
+        for clause in try_.clauses {
+            self.check_block(clause.block);
+        }
+    }
+
+    /// Returns the types returned by the call of a `try` statement, or `None` if the expression
+    /// is not an external function call or a contract creation.
+    fn try_call_returns(&self, expr: &hir::Expr<'_>, ty: Ty<'gcx>) -> Option<&'gcx [Ty<'gcx>]> {
+        let gcx = self.gcx;
+        let msg = "try can only be used with external function calls and contract creation calls";
+        let ExprKind::Call(callee, ..) = expr.kind else {
+            if ty.has_error().is_ok() {
+                gcx.dcx().err(msg).span(expr.span).emit();
+            }
+            return None;
+        };
+        let callee_ty = gcx.type_of_expr_opt(callee.id).unwrap_or(gcx.types.unit);
+        match callee_ty.kind {
+            TyKind::FnPtr(f) if self.is_external_callee(callee, f) => return Some(f.returns),
+            TyKind::FnPtr(f)
+                if matches!(callee.peel_parens().kind, ExprKind::New(_))
+                    && matches!(f.returns, [ret] if matches!(ret.kind, TyKind::Contract(_))) =>
+            {
+                return Some(f.returns)
+            }
+            TyKind::Err(_) => return None,
+            _ => {}
+        }
+        let mut diag = gcx.dcx().err(msg).span(expr.span);
+        if let TyKind::FnPtr(f) = callee_ty.kind {
+            if f.is_external() {
+                diag = diag.help("use `this.f` to refer to a public function `f` externally");
+            }
+        }
+        diag.emit();
+        None
+    }
+
+    /// Checks that the variables of the success clause of a `try` statement match the types
+    /// returned by the call.
+    fn check_try_returns(&self, clause: &hir::TryCatchClause<'_>, returns: &[Ty<'gcx>]) {
+        let gcx = self.gcx;
+        if clause.args.len() != returns.len() {
+            let msg = format!(
+                "function returns {} values, but the `returns` clause declares {} variables",
+                returns.len(),
+                clause.args.len(),
+            );
+            gcx.dcx().err(msg).span(clause.span).emit();
+            return;
+        }
+        for (&id, &expected) in clause.args.iter().zip(returns) {
+            let ty = gcx.type_of_item(id.into());
+            if ty != expected && ty.has_error().is_ok() && expected.has_error().is_ok() {
+                let msg = format!(
+                    "invalid type for return variable: expected `{}`, found `{}`",
+                    expected.display(gcx),
+                    ty.display(gcx),
+                );
+                gcx.dcx().err(msg).span(gcx.hir.variable(id).span).emit();
+            }
+        }
+    }
+
+    /// Checks the catch clauses of a `try` statement.
+    ///
+    /// Only `catch Error(string memory)`, `catch Panic(uint256)`, `catch (bytes memory)` and
+    /// `catch` are supported, at most once each, with the last two being mutually exclusive.
+    fn check_catch_clauses(&self, clauses: &[hir::TryCatchClause<'_>]) {
+        let gcx = self.gcx;
+        let mut seen = [None::<Span>; 3];
+        for clause in clauses {
+            let tys = clause.args.iter().map(|&id| gcx.type_of_item(id.into())).collect::<Vec<_>>();
+            if tys.iter().any(|ty| ty.has_error().is_err()) {
+                continue;
+            }
+            let (kind, valid, expected, duplicate) = match clause.name {
+                None => (
+                    0,
+                    tys.is_empty() || tys == [gcx.types.bytes_ref.memory],
+                    "`catch (bytes memory ...) { ... }` or `catch { ... }`",
+                    "a low-level",
+                ),
+                Some(name) if name.name == sym::Error => (
+                    1,
+                    tys == [gcx.types.string_ref.memory],
+                    "`catch Error(string memory ...) { ... }`",
+                    "an `Error`",
+                ),
+                Some(name) if name.name == sym::Panic => (
+                    2,
+                    tys == [gcx.types.uint(256)],
+                    "`catch Panic(uint256 ...) { ... }`",
+                    "a `Panic`",
+                ),
+                Some(name) => {
+                    let msg = "invalid catch clause name: \
+                               expected `catch (...)`, `catch Error(...)` or `catch Panic(...)`";
+                    gcx.dcx().err(msg).span(name.span).emit();
+                    continue;
+                }
+            };
+            if !valid {
+                gcx.dcx().err(format!("expected {expected}")).span(clause.span).emit();
+            }
+            if let Some(prev) = seen[kind].replace(clause.span) {
+                let msg = format!("this try statement already has {duplicate} catch clause");
+                gcx.dcx().err(msg).span(clause.span).span_note(prev, "previous clause here").emit();
+            }
+        }
+    }
+
+    fn check_decl_multi(
+        &mut self,
+        vars: &[Option<hir::VariableId>],
+        expr: &hir::Expr<'_>,
+        ty: Ty<'gcx>,
+    ) {
+        if ty.has_error().is_err() {
+            return;
+        }
+        let tys = match ty.kind {
+            TyKind::Tuple(tys) => tys,
+            _ => std::slice::from_ref(&ty),
+        };
+        if tys.len() != vars.len() {
+            let msg = format!(
+                "different number of components on the left hand side ({}) than on the right hand side ({})",
+                vars.len(),
+                tys.len(),
+            );
+            self.gcx.dcx().err(msg).span(expr.span).emit();
+            return;
+        }
+        for (&var, &ty) in std::iter::zip(vars, tys) {
+            let Some(var) = var else { continue };
+            let expected = self.gcx.type_of_item(var.into());
+            if !ty.is_implicitly_convertible_to(self.gcx, expected) {
+                let span = self.gcx.hir.variable(var).span;
+                self.report_mismatch(span, ty, expected);
+            }
+        }
+    }
+
+    fn check_return(&mut self, stmt: &hir::Stmt<'_>, expr: Option<&hir::Expr<'_>>) {
+        let Some(expr) = expr else { return };
+        let Some(function) = self.function else {
+            self.check_expr(expr);
+            return;
+        };
+        let returns = self.gcx.type_of_item(function.into()).returns().unwrap();
+        match returns {
+            [] => {
+                self.check_expr(expr);
+                let msg = "function does not declare any return values";
+                self.gcx.dcx().err(msg).span(stmt.span).emit();
+            }
+            &[ret] => {
+                self.check_expr_with(expr, ret);
+            }
+            _ => {
+                let expected = self.gcx.mk_ty(TyKind::Tuple(returns));
+                let ty = self.check_expr(expr);
+                if ty.has_error().is_err() {
+                    return;
+                }
+                let n = match ty.kind {
+                    TyKind::Tuple(tys) => tys.len(),
+                    _ => 1,
+                };
+                if n != returns.len() {
+                    let msg = format!(
+                        "different number of arguments in return statement than in returns declaration: expected {}, found {n}",
+                        returns.len(),
+                    );
+                    self.gcx.dcx().err(msg).span(expr.span).emit();
+                    return;
+                }
+                self.coerce(expr, ty, expected);
+            }
+        }
+    }
+
+    /// Checks the array size expressions contained in the given type.
+    fn check_hir_ty(&mut self, ty: &hir::Type<'_>) {
+        let _ = ty.visit(&self.gcx.hir, &mut |ty| {
+            if let hir::TypeKind::Array(array) = ty.kind {
+                if let Some(size) = array.size {
+                    self.check_expr(size);
+                }
+            }
+            std::ops::ControlFlow::<()>::Continue(())
+        });
+    }
+
+    /// Type checks the given expression and checks that it can be implicitly converted to
+    /// `expected`.
+    fn check_expr_with(&mut self, expr: &hir::Expr<'_>, expected: Ty<'gcx>) -> Ty<'gcx> {
+        let ty = self.check_expr(expr);
+        self.coerce(expr, ty, expected);
+        ty
+    }
+
+    /// Type checks the given expression, recording its type.
+    ///
+    /// Expressions are only checked once: checking an expression again returns the recorded type.
+    fn check_expr(&mut self, expr: &hir::Expr<'_>) -> Ty<'gcx> {
+        if let Some(ty) = self.gcx.type_of_expr_opt(expr.id) {
+            return ty;
+        }
+        let ty = self.check_expr_kind(expr);
+        let _ = self.gcx.typeck_results.expr_types.insert(expr.id, ty);
+        ty
+    }
+
+    fn check_expr_kind(&mut self, expr: &hir::Expr<'_>) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        match expr.kind {
+            ExprKind::Array(exprs) => self.check_array(expr, exprs),
+            ExprKind::Assign(lhs, op, rhs) => {
+                let ty = self.check_expr(lhs);
+                let is_lvalue = self.check_lvalue(lhs, op.is_none());
+                let rhs_ty = self.check_expr(rhs);
+                match op {
+                    Some(op) => {
+                        let result = self.check_binop(expr.span, lhs, ty, op, rhs, rhs_ty);
+                        if is_lvalue {
+                            self.coerce(expr, result, ty);
+                        }
+                    }
+                    None if is_lvalue => self.coerce_assign(lhs, rhs, rhs_ty, ty),
+                    None => {}
+                }
+                ty
+            }
+            ExprKind::Binary(lhs, op, rhs) => {
+                let lhs_ty = self.check_expr(lhs);
+                let rhs_ty = self.check_expr(rhs);
+                match using_for::user_defined_operator(gcx, Either::Right(op.kind), lhs_ty) {
+                    Some(id) => {
+                        self.check_user_defined_operator(expr, id, lhs_ty, Some((rhs, rhs_ty)))
+                    }
+                    None => self.check_binop(expr.span, lhs, lhs_ty, op, rhs, rhs_ty),
+                }
+            }
+            ExprKind::Call(callee, ref args, options) => {
+                self.check_call(expr, callee, args, options)
+            }
+            ExprKind::Delete(inner) => {
+                self.check_expr(inner);
+                self.check_lvalue(inner, false);
+                gcx.types.unit
+            }
+            ExprKind::Ident(res) => match *self.ident_candidates(res) {
+                [res] => {
+                    self.record_res(expr, res);
+                    self.type_of_res(res, expr.span)
+                }
+                // Overloaded identifiers are resolved in `check_call`.
+                ref res => {
+                    let name = self.res_name(res[0]);
+                    let msg =
+                        format!("unable to resolve overloaded identifier `{name}` without a call");
+                    let candidates = res
+                        .iter()
+                        .map(|&res| Member::with_res(name, self.type_of_res(res, expr.span), res))
+                        .collect::<Vec<_>>();
+                    let diag = gcx.dcx().err(msg).span(expr.span);
+                    gcx.mk_ty_err(self.note_candidates(diag, &candidates).emit())
+                }
+            },
+            ExprKind::Index(base, index) => self.check_index(expr, base, index),
+            ExprKind::Slice(base, start, end) => {
+                let ty = self.check_expr(base);
+                for index in [start, end].into_iter().flatten() {
+                    self.check_expr_with(index, gcx.types.uint(256));
+                }
+                match ty.kind {
+                    TyKind::Ref(inner, DataLocation::Calldata)
+                        if matches!(
+                            inner.kind,
+                            TyKind::DynArray(_) | TyKind::Elementary(ElementaryType::Bytes)
+                        ) =>
+                    {
+                        ty
+                    }
+                    TyKind::Err(_) => ty,
+                    _ => {
+                        let msg =
+                            "index range access is only supported for dynamic calldata arrays";
+                        gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit())
+                    }
+                }
+            }
+            ExprKind::Lit(lit) => match lit.kind {
+                LitKind::Str(_, ref s) => gcx.mk_ty_string_literal(s),
+                LitKind::Number(ref n) => self.mk_literal_ty(&BigRational::from_integer(n.clone())),
+                LitKind::Rational(ref r) => self.mk_literal_ty(r),
+                LitKind::Address(_) => gcx.types.address,
+                LitKind::Bool(_) => gcx.types.bool,
+                LitKind::Err(guar) => gcx.mk_ty_err(guar),
+            },
+            ExprKind::Member(base, member) => self.check_member(expr, base, member),
+            ExprKind::New(ref ty) => self.check_new(ty),
+            ExprKind::Payable(inner) => {
+                let ty = self.check_expr(inner);
+                let to = gcx.types.address_payable;
+                if !ty.is_explicitly_convertible_to(gcx, to) {
+                    return gcx.mk_ty_err(self.report_invalid_conversion(expr.span, ty, to));
+                }
+                to
+            }
+            ExprKind::Ternary(cond, true_, false_) => {
+                self.check_expr_with(cond, gcx.types.bool);
+                let true_ty = self.check_expr(true_);
+                let false_ty = self.check_expr(false_);
+                if let Err(guar) = true_ty.has_error().and(false_ty.has_error()) {
+                    return gcx.mk_ty_err(guar);
+                }
+                match self.common_type(true_ty, false_ty) {
+                    Some(ty) => ty,
+                    None => {
+                        let msg = format!(
+                            "true expression's type `{}` does not match false expression's type `{}`",
+                            true_ty.display(gcx),
+                            false_ty.display(gcx),
+                        );
+                        gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit())
+                    }
+                }
+            }
+            ExprKind::Tuple(exprs) => match exprs {
+                &[Some(inner)] => self.check_expr(inner),
+                _ => {
+                    let tys = exprs.iter().map(|expr| match expr {
+                        Some(expr) => self.check_expr(expr),
+                        None => gcx.types.unit,
+                    });
+                    gcx.mk_ty(TyKind::Tuple(gcx.mk_ty_iter(tys)))
+                }
+            },
+            ExprKind::TypeCall(ref ty) => {
+                self.check_hir_ty(ty);
+                gcx.type_of_hir_ty(ty).make_meta(gcx)
+            }
+            ExprKind::Type(ref ty) => {
+                self.check_hir_ty(ty);
+                gcx.type_of_hir_ty(ty).make_type_type(gcx)
+            }
+            ExprKind::Unary(op, inner) => {
+                let ty = self.check_expr(inner);
+                self.check_unop(expr, op, inner, ty)
+            }
+            ExprKind::Err(guar) => gcx.mk_ty_err(guar),
+        }
+    }
+
+    fn type_of_res(&self, res: Res, span: Span) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        match res {
+            // Functions referenced by name are internal function pointers.
+            Res::Item(hir::ItemId::Function(id)) => {
+                let ty = gcx.type_of_item(id.into());
+                let TyKind::FnPtr(f) = ty.kind else { unreachable!() };
+                if f.visibility == hir::Visibility::External {
+                    return ty;
+                }
+                gcx.mk_ty_fn(f.parameters, f.state_mutability, hir::Visibility::Internal, f.returns)
+            }
+            Res::Builtin(builtin @ (Builtin::This | Builtin::Super)) => match self.contract {
+                Some(id) if builtin == Builtin::This => gcx.mk_ty(TyKind::Contract(id)),
+                Some(id) => gcx.mk_ty(TyKind::Super(id)),
+                None => {
+                    let msg = format!("`{}` can only be used inside of contracts", builtin.name());
+                    gcx.mk_ty_err(gcx.dcx().err(msg).span(span).emit())
+                }
+            },
+            _ => gcx.type_of_res(res),
+        }
+    }
+
+    fn check_array(&mut self, expr: &hir::Expr<'_>, exprs: &[hir::Expr<'_>]) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let mut common = None;
+        let mut guar = None;
+        for element in exprs {
+            let ty = self.check_expr(element);
+            if let Err(e) = ty.has_error() {
+                guar = Some(e);
+                continue;
+            }
+            let Some(ty) = self.mobile_type(ty) else {
+                let msg = format!("invalid array element type `{}`", ty.display(gcx));
+                guar = Some(gcx.dcx().err(msg).span(element.span).emit());
+                continue;
+            };
+            common = match common {
+                None => Some(ty),
+                Some(common) => {
+                    let new = self.common_type(common, ty);
+                    if new.is_none() {
+                        let msg = "unable to deduce common type for array elements";
+                        let note = format!(
+                            "previous elements have type `{}`, but this element has type `{}`",
+                            common.display(gcx),
+                            ty.display(gcx),
+                        );
+                        return gcx
+                            .mk_ty_err(gcx.dcx().err(msg).span(element.span).note(note).emit());
+                    }
+                    new
+                }
+            };
+        }
+        if let Some(guar) = guar {
+            return gcx.mk_ty_err(guar);
+        }
+        let Some(element) = common else {
+            let msg = "unable to deduce common type for array elements";
+            return gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit());
+        };
+        gcx.mk_ty(TyKind::Array(element, U256::from(exprs.len())))
+            .with_loc(gcx, DataLocation::Memory)
+    }
+
+    fn check_index(
+        &mut self,
+        expr: &hir::Expr<'_>,
+        base: &hir::Expr<'_>,
+        index: Option<&hir::Expr<'_>>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let base_ty = self.check_expr(base);
+
+        // `T[]` or `T[N]` in an expression.
+        if let TyKind::Type(element) = base_ty.kind {
+            let kind = match index {
+                Some(index) => {
+                    self.check_expr(index);
+                    match ConstantEvaluator::new(gcx).eval(index) {
+                        Ok(int) if int.data.is_zero() => {
+                            let msg = "array length must be greater than zero";
+                            return gcx.mk_ty_err(gcx.dcx().err(msg).span(index.span).emit());
+                        }
+                        Ok(int) => TyKind::Array(element, int.data),
+                        Err(guar) => return gcx.mk_ty_err(guar),
+                    }
+                }
+                None => TyKind::DynArray(element),
+            };
+            return gcx.mk_ty(kind).make_type_type(gcx);
+        }
+
+        let Some(index) = index else {
+            let guar = base_ty.has_error().err().unwrap_or_else(|| {
+                let msg = "index expression cannot be omitted";
+                gcx.dcx().err(msg).span(expr.span).emit()
+            });
+            return gcx.mk_ty_err(guar);
+        };
+
+        let (inner, loc) = match base_ty.kind {
+            TyKind::Ref(inner, loc) => (inner, Some(loc)),
+            _ => (base_ty, None),
+        };
+        let uint256 = gcx.types.uint(256);
+        let with_loc = |ty: Ty<'gcx>| match loc {
+            Some(loc) if ty.is_reference_type() => ty.with_loc(gcx, loc),
+            _ => ty,
+        };
+        match inner.kind {
+            TyKind::DynArray(element) | TyKind::Array(element, _) if loc.is_some() => {
+                self.check_expr_with(index, uint256);
+                with_loc(element)
+            }
+            TyKind::Elementary(ElementaryType::Bytes) if loc.is_some() => {
+                self.check_expr_with(index, uint256);
+                gcx.types.fixed_bytes(1)
+            }
+            TyKind::Elementary(ElementaryType::FixedBytes(_)) => {
+                self.check_expr_with(index, uint256);
+                gcx.types.fixed_bytes(1)
+            }
+            TyKind::Mapping(key, value) => {
+                self.check_expr_with(index, key);
+                if value.is_reference_type() {
+                    value.with_loc(gcx, DataLocation::Storage)
+                } else {
+                    value
+                }
+            }
+            TyKind::Err(guar) => {
+                self.check_expr(index);
+                gcx.mk_ty_err(guar)
+            }
+            _ => {
+                self.check_expr(index);
+                let msg = format!("cannot index into a value of type `{}`", base_ty.display(gcx));
+                gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit())
+            }
+        }
+    }
+
+    fn check_new(&mut self, hir_ty: &hir::Type<'_>) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        self.check_hir_ty(hir_ty);
+        let ty = gcx.type_of_hir_ty(hir_ty);
+        match ty.kind {
+            TyKind::Contract(id) => {
+                let contract = gcx.hir.contract(id);
+                if contract.kind.is_interface()
+                    || contract.kind.is_library()
+                    || contract.is_abstract()
+                {
+                    let article = if contract.kind.is_library() { "a" } else { "an" };
+                    let msg = format!("cannot instantiate {article} {}", contract.kind.to_str());
+                    return gcx.mk_ty_err(gcx.dcx().err(msg).span(hir_ty.span).emit());
+                }
+                let (parameters, state_mutability) = match contract.ctor {
+                    Some(ctor) => {
+                        let ctor_ty = gcx.type_of_item(ctor.into());
+                        (ctor_ty.parameters().unwrap(), ctor_ty.state_mutability().unwrap())
+                    }
+                    None => (&[][..], StateMutability::NonPayable),
+                };
+                gcx.mk_ty_fn(parameters, state_mutability, hir::Visibility::Internal, &[ty])
+            }
+            TyKind::DynArray(_)
+            | TyKind::Elementary(ElementaryType::Bytes | ElementaryType::String) => gcx
+                .mk_builtin_fn(
+                    &[gcx.types.uint(256)],
+                    StateMutability::Pure,
+                    &[ty.with_loc(gcx, DataLocation::Memory)],
+                ),
+            TyKind::Err(_) => ty,
+            _ => {
+                let msg = "contract or array type expected";
+                gcx.mk_ty_err(gcx.dcx().err(msg).span(hir_ty.span).emit())
+            }
+        }
+    }
+
+    fn check_call(
+        &mut self,
+        expr: &hir::Expr<'_>,
+        callee: &hir::Expr<'_>,
+        args: &hir::CallArgs<'_>,
+        options: Option<&[hir::NamedArg<'_>]>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let inner = callee.peel_parens();
+        if let Some(ty) = self.check_require_with_error(inner, args) {
+            return ty;
+        }
+        if gcx.type_of_expr_opt(inner.id).is_none() {
+            if let Some(candidates) = self.callee_candidates(inner) {
+                if candidates.len() > 1 {
+                    let ty = self.resolve_overloaded_call(inner, &candidates, args);
+                    let _ = gcx.typeck_results.expr_types.insert(inner.id, ty);
+                }
+            }
+        }
+        let callee_ty = self.check_expr(callee);
+        let callee_member = match inner.kind {
+            ExprKind::Member(..) => gcx.member_of_expr(inner.id),
+            _ => None,
+        };
+        let callee_res = match inner.kind {
+            ExprKind::Member(..) => callee_member.and_then(|m| m.res),
+            _ => gcx.res_of_expr(inner.id),
+        };
+        if let Some(options) = options {
+            self.check_call_options(inner, callee_ty, callee_res, options);
+        }
+
+        let callee_item = match callee_res {
+            Some(Res::Item(id)) => Some(id),
+            Some(Res::Builtin(
+                builtin @ (Builtin::AbiEncode
+                | Builtin::AbiEncodePacked
+                | Builtin::AbiEncodeWithSelector
+                | Builtin::AbiEncodeCall
+                | Builtin::AbiEncodeWithSignature
+                | Builtin::AbiDecode
+                | Builtin::StringConcat
+                | Builtin::BytesConcat),
+            )) => return self.check_variadic_builtin_call(expr, builtin, args),
+            _ => None,
+        };
+        match callee_ty.kind {
+            TyKind::FnPtr(f) => {
+                if self.is_called_via_contract_name(inner) {
+                    let msg = "cannot call function via contract type name";
+                    gcx.dcx().err(msg).span(callee.span).emit();
+                }
+                let mut names = callee_item.and_then(|id| gcx.item_parameters_opt(id));
+                // The first parameter of attached functions is bound to the base expression.
+                if callee_member.is_some_and(|m| m.attached) {
+                    names = names.map(|names| &names[1..]);
+                }
+                self.check_call_args(expr.span, args, f.parameters, names, "function");
+                match f.returns {
+                    [] => gcx.types.unit,
+                    &[ret] => ret,
+                    returns => gcx.mk_ty(TyKind::Tuple(returns)),
+                }
+            }
+            TyKind::Event(parameters, id) => {
+                if self.event_or_error_call != Some(expr.id) {
+                    let msg = "event invocations have to be prefixed by `emit`";
+                    gcx.dcx().err(msg).span(expr.span).emit();
+                }
+                let names = Some(gcx.item_parameters(id));
+                self.check_call_args(expr.span, args, parameters, names, "event");
+                gcx.types.unit
+            }
+            TyKind::Error(parameters, id) => {
+                if self.event_or_error_call != Some(expr.id) {
+                    let name = gcx.item_name(id);
+                    gcx.dcx()
+                        .err("errors can only be used with `revert` statements")
+                        .span(expr.span)
+                        .help(format!(
+                            "use `revert {name}(...)` or `require(condition, {name}(...))`"
+                        ))
+                        .emit();
+                }
+                let names = Some(gcx.item_parameters(id));
+                self.check_call_args(expr.span, args, parameters, names, "error");
+                gcx.types.unit
+            }
+            TyKind::Type(to) => match to.kind {
+                TyKind::Struct(id) => {
+                    // Struct fields are stored in storage, but the constructor copies its
+                    // arguments to memory.
+                    let fields = gcx.mk_ty_iter(gcx.struct_field_types(id).iter().map(|&ty| {
+                        if ty.is_ref_at(DataLocation::Storage) {
+                            ty.with_loc(gcx, DataLocation::Memory)
+                        } else {
+                            ty
+                        }
+                    }));
+                    let names = Some(gcx.hir.strukt(id).fields);
+                    self.check_call_args(expr.span, args, fields, names, "struct constructor");
+                    to.with_loc(gcx, DataLocation::Memory)
+                }
+                _ => self.check_explicit_conversion(expr, args, to),
+            },
+            TyKind::Err(guar) => {
+                for arg in args.exprs() {
+                    self.check_expr(arg);
+                }
+                gcx.mk_ty_err(guar)
+            }
+            _ => {
+                for arg in args.exprs() {
+                    self.check_expr(arg);
+                }
+                let msg = format!("expected function, found `{}`", callee_ty.display(gcx));
+                gcx.mk_ty_err(gcx.dcx().err(msg).span(callee.span).emit())
+            }
+        }
+    }
+
+    /// Checks `require(condition, Error(...))`, which reverts with a custom error.
+    ///
+    /// Returns `None` if the call is not a `require` call with an error argument.
+    fn check_require_with_error(
+        &mut self,
+        callee: &hir::Expr<'_>,
+        args: &hir::CallArgs<'_>,
+    ) -> Option<Ty<'gcx>> {
+        let gcx = self.gcx;
+        let ExprKind::Ident(res) = callee.kind else { return None };
+        if !res.contains(&Res::Builtin(Builtin::Require)) {
+            return None;
+        }
+        let hir::CallArgs::Unnamed([cond, error]) = *args else { return None };
+        let error = error.peel_parens();
+        let ExprKind::Call(error_callee, ..) = error.kind else { return None };
+        let candidates = self.callee_candidates(error_callee.peel_parens())?;
+        if candidates.is_empty()
+            || !candidates.iter().all(|c| matches!(c.ty.kind, TyKind::Error(..)))
+        {
+            return None;
+        }
+
+        self.check_expr_with(cond, gcx.types.bool);
+        self.event_or_error_call = Some(error.id);
+        let error_ty = self.check_expr(error_callee);
+        self.check_expr(error);
+        self.event_or_error_call = None;
+
+        let ty = gcx.mk_builtin_fn(&[gcx.types.bool, error_ty], StateMutability::Pure, &[]);
+        self.record_res(callee, Res::Builtin(Builtin::Require));
+        let _ = gcx.typeck_results.expr_types.insert(callee.id, ty);
+        Some(gcx.types.unit)
+    }
+
+    /// Checks a call to a builtin function whose parameters are variadic or depend on its
+    /// arguments, like `abi.encode` and `abi.decode`.
+    fn check_variadic_builtin_call(
+        &mut self,
+        expr: &hir::Expr<'_>,
+        builtin: Builtin,
+        args: &hir::CallArgs<'_>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let hir::CallArgs::Unnamed(args) = *args else {
+            for arg in args.exprs() {
+                self.check_expr(arg);
+            }
+            let msg = "named arguments cannot be used for functions that take arbitrary parameters";
+            return gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit());
+        };
+        let bytes = gcx.types.bytes_ref.memory;
+        let string = gcx.types.string_ref.memory;
+
+        // The leading arguments with a fixed type.
+        let fixed = match builtin {
+            Builtin::AbiEncodeWithSelector => &[gcx.types.fixed_bytes(4)][..],
+            Builtin::AbiEncodeWithSignature => &[string][..],
+            Builtin::AbiEncodeCall => return self.check_abi_encode_call(expr, args),
+            Builtin::AbiDecode => return self.check_abi_decode(expr, args),
+            _ => &[],
+        };
+        if args.len() < fixed.len() {
+            for arg in args {
+                self.check_expr(arg);
+            }
+            let msg = format!(
+                "wrong argument count for function call: {} arguments given but expected at least \
+                 {}",
+                args.len(),
+                fixed.len(),
+            );
+            return gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit());
+        }
+        let (fixed_args, args) = args.split_at(fixed.len());
+        for (arg, &ty) in std::iter::zip(fixed_args, fixed) {
+            self.check_expr_with(arg, ty);
+        }
+
+        match builtin {
+            Builtin::StringConcat => {
+                for arg in args {
+                    self.check_expr_with(arg, string);
+                }
+                string
+            }
+            Builtin::BytesConcat => {
+                for arg in args {
+                    let ty = self.check_expr(arg);
+                    let valid = matches!(
+                        ty.peel_refs().kind,
+                        TyKind::Elementary(ElementaryType::Bytes | ElementaryType::FixedBytes(_))
+                            | TyKind::StringLiteral(..)
+                            | TyKind::Err(_)
+                    );
+                    if !valid {
+                        let msg = format!(
+                            "invalid type for argument in `bytes.concat`: \
+                             expected `bytes` or fixed bytes, found `{}`",
+                            ty.display(gcx),
+                        );
+                        gcx.dcx().err(msg).span(arg.span).emit();
+                    }
+                }
+                bytes
+            }
+            _ => {
+                let packed = builtin == Builtin::AbiEncodePacked;
+                for arg in args {
+                    let ty = self.check_expr(arg);
+                    self.check_abi_encodable(arg.span, ty, packed);
+                }
+                bytes
+            }
+        }
+    }
+
+    /// Checks `abi.encodeCall(f, (args...))` against the parameters of the external function `f`.
+    fn check_abi_encode_call(&mut self, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>]) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let bytes = gcx.types.bytes_ref.memory;
+        let [callee, call_args] = args else {
+            for arg in args {
+                self.check_expr(arg);
+            }
+            let msg = "expected two arguments: a function pointer followed by a tuple";
+            return gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit());
+        };
+        let callee_ty = self.check_expr(callee);
+        let f = match callee_ty.kind {
+            TyKind::FnPtr(f) if self.is_external_callee(callee, f) => f,
+            TyKind::Err(_) => {
+                self.check_expr(call_args);
+                return bytes;
+            }
+            _ => {
+                self.check_expr(call_args);
+                let mut diag = gcx
+                    .dcx()
+                    .err("expected an external function as the first argument of `abi.encodeCall`")
+                    .span(callee.span);
+                if let TyKind::FnPtr(_) = callee_ty.kind {
+                    diag = diag.help("use `this.f` to refer to a public function `f` externally");
+                }
+                diag.emit();
+                return bytes;
+            }
+        };
+
+        let n_args = match call_args.peel_parens().kind {
+            ExprKind::Tuple(exprs) if exprs.len() != 1 => exprs.len(),
+            _ => 1,
+        };
+        if n_args != f.parameters.len() {
+            self.check_expr(call_args);
+            let msg = format!(
+                "wrong argument count for function call: {n_args} arguments given but expected {}",
+                f.parameters.len(),
+            );
+            gcx.dcx().err(msg).span(call_args.span).emit();
+            return bytes;
+        }
+        let expected = match f.parameters {
+            &[param] => param,
+            params => gcx.mk_ty(TyKind::Tuple(params)),
+        };
+        self.check_expr_with(call_args, expected);
+        bytes
+    }
+
+    /// Returns `true` if calling `callee` of type `f` performs an external call.
+    fn is_external_callee(&self, callee: &hir::Expr<'_>, f: &TyFnPtr<'gcx>) -> bool {
+        // Functions referenced by name are internal, even if they are declared as public.
+        f.is_external()
+            && !matches!(
+                self.gcx.res_of_expr(callee.peel_parens().id),
+                Some(Res::Item(hir::ItemId::Function(_)))
+            )
+    }
+
+    /// Checks `abi.decode(data, (T...))`, returning the decoded types.
+    fn check_abi_decode(&mut self, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>]) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let [data, types] = args else {
+            for arg in args {
+                self.check_expr(arg);
+            }
+            let msg = format!(
+                "wrong argument count for function call: {} arguments given but expected 2",
+                args.len(),
+            );
+            return gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit());
+        };
+        self.check_expr_with(data, gcx.types.bytes_ref.memory);
+        self.check_expr(types);
+
+        let type_exprs = match types.peel_parens().kind {
+            ExprKind::Tuple(exprs) if exprs.len() != 1 => exprs.to_vec(),
+            _ => vec![Some(types)],
+        };
+        let mut decoded = Vec::with_capacity(type_exprs.len());
+        for type_expr in type_exprs {
+            let Some(type_expr) = type_expr else {
+                let msg = "tuple component cannot be empty";
+                decoded.push(gcx.mk_ty_err(gcx.dcx().err(msg).span(types.span).emit()));
+                continue;
+            };
+            let ty = match self.check_expr(type_expr).kind {
+                TyKind::Type(ty) => ty,
+                TyKind::Err(guar) => gcx.mk_ty_err(guar),
+                _ => {
+                    let msg = "expected a type name";
+                    gcx.mk_ty_err(gcx.dcx().err(msg).span(type_expr.span).emit())
+                }
+            };
+            self.check_abi_encodable(type_expr.span, ty, false);
+            decoded.push(if ty.is_reference_type() {
+                ty.with_loc(gcx, DataLocation::Memory)
+            } else {
+                ty
+            });
+        }
+        match &decoded[..] {
+            [] => gcx.types.unit,
+            &[ty] => ty,
+            tys => gcx.mk_ty(TyKind::Tuple(gcx.mk_tys(tys))),
+        }
+    }
+
+    /// Checks that a value of type `ty` can be ABI-encoded, or encoded with the non-standard packed
+    /// encoding if `packed` is `true`.
+    fn check_abi_encodable(&self, span: Span, ty: Ty<'gcx>, packed: bool) {
+        let gcx = self.gcx;
+        if ty.has_error().is_err() {
+            return;
+        }
+        let msg = if is_literal(ty) && packed {
+            Some(
+                "cannot perform packed encoding for a literal; \
+                 convert it to an explicit type first",
+            )
+        } else if self.mobile_type(ty).is_none() {
+            Some("cannot encode a literal that cannot be represented by any type")
+        } else {
+            None
+        };
+        if let Some(msg) = msg {
+            gcx.dcx().err(msg).span(span).emit();
+            return;
+        }
+
+        let ty = self.mobile_type(ty).unwrap();
+        let encodable = ty.can_be_exported()
+            && ty
+                .visit(&mut |ty| match ty.kind {
+                    TyKind::FnPtr(f) if !f.is_external() => std::ops::ControlFlow::Break(()),
+                    TyKind::Mapping(..)
+                    | TyKind::Tuple(_)
+                    | TyKind::Type(_)
+                    | TyKind::Meta(_)
+                    | TyKind::Super(_)
+                    | TyKind::Module(_)
+                    | TyKind::BuiltinModule(_)
+                    | TyKind::Event(..)
+                    | TyKind::Error(..) => std::ops::ControlFlow::Break(()),
+                    _ => std::ops::ControlFlow::Continue(()),
+                })
+                .is_continue();
+        if !encodable {
+            let msg = format!("type `{}` cannot be ABI-encoded", ty.display(gcx));
+            gcx.dcx().err(msg).span(span).emit();
+            return;
+        }
+
+        // Structs and nested arrays have no packed encoding.
+        let supported_packed = match ty.peel_refs().kind {
+            TyKind::Struct(_) => false,
+            TyKind::Array(element, _) | TyKind::DynArray(element) => {
+                !element.is_reference_type() && !matches!(element.kind, TyKind::FnPtr(_))
+            }
+            _ => true,
+        };
+        if packed && !supported_packed {
+            let msg = format!("type `{}` is not supported in packed mode", ty.display(gcx));
+            gcx.dcx().err(msg).span(span).emit();
+        }
+    }
+
+    /// Checks the `{value: ..., gas: ..., salt: ...}` options of a call.
+    fn check_call_options(
+        &mut self,
+        callee: &hir::Expr<'_>,
+        callee_ty: Ty<'gcx>,
+        callee_res: Option<Res>,
+        options: &[hir::NamedArg<'_>],
+    ) {
+        let gcx = self.gcx;
+        let is_creation = matches!(callee.kind, ExprKind::New(_));
+        let is_bare_call = matches!(
+            callee_res,
+            Some(Res::Builtin(
+                Builtin::AddressCall | Builtin::AddressDelegatecall | Builtin::AddressStaticcall
+            ))
+        );
+        let f = match callee_ty.kind {
+            TyKind::FnPtr(f) if is_creation || is_bare_call || f.is_external() => Some(f),
+            _ => None,
+        };
+        if f.is_none() && callee_ty.has_error().is_ok() {
+            let msg =
+                "function call options can only be set on external function calls or contract creations";
+            let span = options[0].name.span.to(options[options.len() - 1].value.span);
+            gcx.dcx().err(msg).span(span).emit();
+        }
+
+        for (i, option) in options.iter().enumerate() {
+            let name = option.name;
+            let expected = match name.name {
+                sym::value | kw::Gas => gcx.types.uint(256),
+                sym::salt => gcx.types.fixed_bytes(32),
+                _ => {
+                    self.check_expr(&option.value);
+                    let msg = format!(
+                        "unknown call option `{name}`; valid options are `salt`, `value` and `gas`"
+                    );
+                    gcx.dcx().err(msg).span(name.span).emit();
+                    continue;
+                }
+            };
+            self.check_expr_with(&option.value, expected);
+            let Some(f) = f else { continue };
+            let msg = if options[..i].iter().any(|prev| prev.name.name == name.name) {
+                format!("duplicate option `{name}`")
+            } else if name.name == sym::value && f.state_mutability != StateMutability::Payable {
+                "cannot set option `value` on a non-payable function type".to_string()
+            } else if name.name == kw::Gas && is_creation {
+                "function call option `gas` cannot be used with `new`".to_string()
+            } else if name.name == sym::salt && !is_creation {
+                "function call option `salt` can only be used with `new`".to_string()
+            } else {
+                continue;
+            };
+            gcx.dcx().err(msg).span(name.span).emit();
+        }
+    }
+
+    /// Returns the declarations that the callee of a call can refer to, if it is an identifier or
+    /// a member access.
+    fn callee_candidates(&mut self, callee: &hir::Expr<'_>) -> Option<Vec<Member<'gcx>>> {
+        match callee.kind {
+            ExprKind::Ident(res) => Some(
+                self.ident_candidates(res)
+                    .into_iter()
+                    .map(|res| {
+                        let ty = self.type_of_res(res, callee.span);
+                        Member::with_res(self.res_name(res), ty, res)
+                    })
+                    .collect(),
+            ),
+            ExprKind::Member(base, member) => {
+                let base_ty = self.check_expr(base);
+                Some(self.member_candidates(base_ty, member))
+            }
+            _ => None,
+        }
+    }
+
+    /// Selects the declaration that an overloaded callee refers to, based on the types of the
+    /// call arguments, and returns its type.
+    fn resolve_overloaded_call(
+        &mut self,
+        callee: &hir::Expr<'_>,
+        candidates: &[Member<'gcx>],
+        args: &hir::CallArgs<'_>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let arg_tys = args.exprs().map(|arg| self.check_expr(arg)).collect::<Vec<_>>();
+        let matching = candidates
+            .iter()
+            .copied()
+            .filter(|&candidate| self.overload_matches(candidate, args, &arg_tys))
+            .collect::<Vec<_>>();
+        let name = candidates[0].name;
+        let msg = match *matching {
+            [candidate] => {
+                self.record_candidate(callee, candidate);
+                return candidate.ty;
+            }
+            [] => format!("no matching overload found for `{name}` with the given arguments"),
+            _ => format!("ambiguous call to overloaded `{name}`: multiple declarations match"),
+        };
+        let notes = if matching.is_empty() { candidates } else { &matching[..] };
+        let diag = gcx.dcx().err(msg).span(callee.span);
+        gcx.mk_ty_err(self.note_candidates(diag, notes).emit())
+    }
+
+    /// Returns `true` if the overload candidate can be called with the given arguments.
+    fn overload_matches(
+        &self,
+        candidate: Member<'gcx>,
+        args: &hir::CallArgs<'_>,
+        arg_tys: &[Ty<'gcx>],
+    ) -> bool {
+        let gcx = self.gcx;
+        let Some(parameters) = candidate.ty.parameters() else { return false };
+        if parameters.len() != arg_tys.len() {
+            return false;
+        }
+        let convertible = |arg: &hir::Expr<'_>, arg_ty: Ty<'gcx>, param: Ty<'gcx>| {
+            arg_ty.is_implicitly_convertible_to(gcx, param) || self.is_hex_literal_of(arg, param)
+        };
+        match *args {
+            hir::CallArgs::Unnamed(args) => std::iter::zip(args, arg_tys)
+                .zip(parameters)
+                .all(|((arg, &arg_ty), &param)| convertible(arg, arg_ty, param)),
+            hir::CallArgs::Named(args) => {
+                let Some(Res::Item(id)) = candidate.res else { return false };
+                let Some(mut names) = gcx.item_parameters_opt(id) else { return false };
+                if candidate.attached {
+                    names = &names[1..];
+                }
+                std::iter::zip(args, arg_tys).enumerate().all(|(i, (arg, &arg_ty))| {
+                    !args[..i].iter().any(|prev| prev.name.name == arg.name.name)
+                        && names
+                            .iter()
+                            .position(|&id| {
+                                gcx.item_name_opt(id).is_some_and(|n| n.name == arg.name.name)
+                            })
+                            .is_some_and(|param| convertible(&arg.value, arg_ty, parameters[param]))
+                })
+            }
+        }
+    }
+
+    fn note_candidates<'a>(
+        &self,
+        mut diag: DiagBuilder<'a, ErrorGuaranteed>,
+        candidates: &[Member<'gcx>],
+    ) -> DiagBuilder<'a, ErrorGuaranteed> {
+        for candidate in candidates {
+            let note = format!("candidate: `{}`", candidate.ty.display(self.gcx));
+            diag = match candidate.res {
+                Some(Res::Item(id)) => diag.span_note(self.gcx.item_name(id).span, note),
+                _ => diag.note(note),
+            };
+        }
+        diag
+    }
+
+    /// Returns the declarations that an identifier expression can refer to.
+    ///
+    /// Getters of public state variables are only accessible externally, as in `this.x()`.
+    fn ident_candidates(&self, res: &[Res]) -> SmallVec<[Res; 2]> {
+        res.iter()
+            .copied()
+            .filter(|res| match *res {
+                Res::Item(hir::ItemId::Function(id)) => !self.gcx.hir.function(id).is_getter(),
+                _ => true,
+            })
+            .collect()
+    }
+
+    fn res_name(&self, res: Res) -> Symbol {
+        match res {
+            Res::Item(id) => self.gcx.item_name(id).name,
+            Res::Builtin(builtin) => builtin.name(),
+            Res::Namespace(_) | Res::Err(_) => kw::Empty,
+        }
+    }
+
+    /// Returns the members of `ty` with the given name.
+    fn member_candidates(&self, ty: Ty<'gcx>, name: Ident) -> Vec<Member<'gcx>> {
+        self.members_of(ty).filter(|m| m.name == name.name).collect()
+    }
+
+    /// Returns the members of `ty`, as accessible from the current contract.
+    ///
+    /// Members of base contracts accessed through their name refer to the internal declarations,
+    /// as in `Base.f()`. Functions attached with `using for` directives are included.
+    fn members_of(&self, ty: Ty<'gcx>) -> impl Iterator<Item = Member<'gcx>> {
+        let gcx = self.gcx;
+        if let TyKind::Type(inner) = ty.kind {
+            if let TyKind::Contract(id) = inner.kind {
+                if self.is_base_contract(id) {
+                    return Either::Left(members::base_contract(gcx, id).into_iter());
+                }
+            }
+        }
+        let attached = gcx.attached_functions((self.source, self.contract, ty));
+        Either::Right(gcx.members_of(ty).iter().chain(attached).copied())
+    }
+
+    /// Returns `true` if `id` is a non-library contract that the current contract inherits from,
+    /// or the current contract itself.
+    fn is_base_contract(&self, id: hir::ContractId) -> bool {
+        let gcx = self.gcx;
+        !gcx.hir.contract(id).kind.is_library()
+            && self.contract.is_some_and(|c| gcx.hir.contract(c).linearized_bases.contains(&id))
+    }
+
+    /// Returns `true` if `callee` is a function accessed through the name of a contract that is
+    /// neither a library nor a base of the current contract, as in `C.f()`.
+    fn is_called_via_contract_name(&self, callee: &hir::Expr<'_>) -> bool {
+        let ExprKind::Member(base, _) = callee.kind else { return false };
+        let Some(base_ty) = self.gcx.type_of_expr_opt(base.id) else { return false };
+        let TyKind::Type(inner) = base_ty.kind else { return false };
+        let TyKind::Contract(id) = inner.kind else { return false };
+        !self.gcx.hir.contract(id).kind.is_library() && !self.is_base_contract(id)
+    }
+
+    /// Type checks a member access expression that is not the callee of a call.
+    fn check_member(
+        &mut self,
+        expr: &hir::Expr<'_>,
+        base: &hir::Expr<'_>,
+        name: Ident,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let base_ty = self.check_expr(base);
+        if base_ty.has_error().is_err() {
+            return base_ty;
+        }
+        let candidates = self.member_candidates(base_ty, name);
+        match *candidates {
+            [member] => {
+                self.record_candidate(expr, member);
+                member.ty
+            }
+            [] => gcx.mk_ty_err(self.report_member_not_found(base_ty, name)),
+            // Overloaded members are resolved in `check_call`.
+            _ => {
+                let msg = format!("unable to resolve overloaded member `{name}` without a call");
+                let diag = gcx.dcx().err(msg).span(name.span);
+                gcx.mk_ty_err(self.note_candidates(diag, &candidates).emit())
+            }
+        }
+    }
+
+    fn report_member_not_found(&self, ty: Ty<'gcx>, name: Ident) -> ErrorGuaranteed {
+        let gcx = self.gcx;
+        // Removed in 0.7.0 in favor of call options.
+        if let TyKind::FnPtr(f) = ty.kind {
+            if f.is_external() && (name.name == sym::value || name.name == kw::Gas) {
+                let msg = format!("using `.{name}(...)` is deprecated");
+                let help = format!("use `{{{name}: ...}}` instead");
+                return gcx.dcx().err(msg).span(name.span).help(help).emit();
+            }
+        }
+        let msg = format!("member `{name}` not found on type `{}`", ty.display(gcx));
+        let mut diag = gcx.dcx().err(msg).span(name.span);
+        if let Some(similar) = find_similar_name(self.members_of(ty).map(|m| m.name), name.name) {
+            diag = diag.help(format!("a member with a similar name exists: `{similar}`"));
+        }
+        diag.emit()
+    }
+
+    /// Records the declaration that an identifier or member access expression resolved to.
+    fn record_candidate(&self, expr: &hir::Expr<'_>, candidate: Member<'gcx>) {
+        let results = &self.gcx.typeck_results;
+        match expr.kind {
+            ExprKind::Ident(_) => {
+                if let Some(res) = candidate.res {
+                    self.record_res(expr, res);
+                }
+            }
+            ExprKind::Member(..) => {
+                let _ = results.expr_members.insert(expr.id, candidate);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Records the resolution of an identifier expression, or the function implementing a
+    /// user-defined operator.
+    fn record_res(&self, expr: &hir::Expr<'_>, res: Res) {
+        let _ = self.gcx.typeck_results.expr_resolutions.insert(expr.id, res);
+    }
+
+    fn check_explicit_conversion(
+        &mut self,
+        expr: &hir::Expr<'_>,
+        args: &hir::CallArgs<'_>,
+        to: Ty<'gcx>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        let arg = match args {
+            hir::CallArgs::Unnamed([arg]) => arg,
+            _ => {
+                for arg in args.exprs() {
+                    self.check_expr(arg);
+                }
+                let msg = "exactly one argument expected for explicit type conversion";
+                return gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit());
+            }
+        };
+        let from = self.check_expr(arg);
+        let to = if to.is_reference_type() {
+            let loc = match from.kind {
+                TyKind::Ref(_, loc) => loc,
+                _ => DataLocation::Memory,
+            };
+            to.with_loc(gcx, loc)
+        } else {
+            to
+        };
+        if !from.is_explicitly_convertible_to(gcx, to) && !self.is_hex_literal_of(arg, to) {
+            return gcx.mk_ty_err(self.report_invalid_conversion(expr.span, from, to));
+        }
+        to
+    }
+
+    fn report_invalid_conversion(
+        &self,
+        span: Span,
+        from: Ty<'gcx>,
+        to: Ty<'gcx>,
+    ) -> ErrorGuaranteed {
+        let gcx = self.gcx;
+        let msg = format!(
+            "explicit type conversion not allowed from `{}` to `{}`",
+            from.display(gcx),
+            to.display(gcx),
+        );
+        let mut diag = gcx.dcx().err(msg).span(span);
+        // User-defined value types are converted with `wrap` and `unwrap`.
+        match (&from.kind, &to.kind) {
+            (&TyKind::Udvt(underlying, id), _)
+                if underlying.is_implicitly_convertible_to(gcx, to) =>
+            {
+                let name = gcx.item_name(id);
+                diag = diag
+                    .help(format!("use `{name}.unwrap(...)` to convert to the underlying type"));
+            }
+            (_, &TyKind::Udvt(underlying, id))
+                if from.is_implicitly_convertible_to(gcx, underlying) =>
+            {
+                let name = gcx.item_name(id);
+                diag = diag
+                    .help(format!("use `{name}.wrap(...)` to convert from the underlying type"));
+            }
+            _ => {}
+        }
+        diag.emit()
+    }
+
+    /// Checks the arguments of a call against the given parameter types.
+    ///
+    /// `names` are the parameter declarations, used to match named arguments.
+    fn check_call_args(
+        &mut self,
+        span: Span,
+        args: &hir::CallArgs<'_>,
+        parameters: &[Ty<'gcx>],
+        names: Option<&[hir::VariableId]>,
+        descr: &str,
+    ) {
+        let gcx = self.gcx;
+        if args.len() != parameters.len() {
+            for arg in args.exprs() {
+                self.check_expr(arg);
+            }
+            let msg = format!(
+                "wrong argument count for {descr} call: {} arguments given but expected {}",
+                args.len(),
+                parameters.len(),
+            );
+            gcx.dcx().err(msg).span(span).emit();
+            return;
+        }
+        match *args {
+            hir::CallArgs::Unnamed(args) => {
+                for (arg, &param) in std::iter::zip(args, parameters) {
+                    self.check_expr_with(arg, param);
+                }
+            }
+            hir::CallArgs::Named(args) => {
+                let Some(names) = names else {
+                    for arg in args {
+                        self.check_expr(&arg.value);
+                    }
+                    let msg = "named arguments cannot be used when calling a function pointer";
+                    gcx.dcx().err(msg).span(span).emit();
+                    return;
+                };
+                for (i, arg) in args.iter().enumerate() {
+                    if args[..i].iter().any(|prev| prev.name.name == arg.name.name) {
+                        self.check_expr(&arg.value);
+                        let msg = format!("duplicate named argument `{}`", arg.name);
+                        gcx.dcx().err(msg).span(arg.name.span).emit();
+                        continue;
+                    }
+                    let param = names.iter().position(|&id| {
+                        gcx.item_name_opt(id).is_some_and(|n| n.name == arg.name.name)
+                    });
+                    match param {
+                        Some(param) => {
+                            self.check_expr_with(&arg.value, parameters[param]);
+                        }
+                        None => {
+                            self.check_expr(&arg.value);
+                            let msg = format!(
+                                "named argument `{}` does not match {descr} declaration",
+                                arg.name
+                            );
+                            gcx.dcx().err(msg).span(arg.name.span).emit();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_unop(
+        &mut self,
+        expr: &hir::Expr<'_>,
+        op: hir::UnOp,
+        inner: &hir::Expr<'_>,
+        ty: Ty<'gcx>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        if ty.has_error().is_err() {
+            return ty;
+        }
+        if let Some(id) = using_for::user_defined_operator(gcx, Either::Left(op.kind), ty) {
+            return self.check_user_defined_operator(expr, id, ty, None);
+        }
+        let valid = match op.kind {
+            UnOpKind::Not => ty.peel_refs() == gcx.types.bool,
+            UnOpKind::Neg => match ty.kind {
+                TyKind::IntLiteral(..) | TyKind::RationalLiteral(_) => {
+                    return self.literal_value(inner).map_or(ty, |v| self.mk_literal_ty(&-v));
+                }
+                TyKind::Elementary(ElementaryType::Int(_)) => true,
+                _ => false,
+            },
+            UnOpKind::BitNot => match ty.kind {
+                TyKind::IntLiteral(..) => {
+                    let v = self.literal_value(inner);
+                    return v.map_or(ty, |v| self.mk_literal_ty(&(-v - BigRational::one())));
+                }
+                TyKind::Elementary(
+                    ElementaryType::Int(_)
+                    | ElementaryType::UInt(_)
+                    | ElementaryType::FixedBytes(_),
+                ) => true,
+                _ => false,
+            },
+            UnOpKind::PreInc | UnOpKind::PreDec | UnOpKind::PostInc | UnOpKind::PostDec => {
+                self.check_lvalue(inner, false);
+                matches!(
+                    ty.kind,
+                    TyKind::Elementary(ElementaryType::Int(_) | ElementaryType::UInt(_))
+                )
+            }
+        };
+        if valid {
+            ty
+        } else {
+            let msg = format!(
+                "cannot apply unary operator `{}` to type `{}`",
+                op.kind.to_str(),
+                ty.display(gcx)
+            );
+            gcx.mk_ty_err(gcx.dcx().err(msg).span(expr.span).emit())
+        }
+    }
+
+    /// Type checks an operation on a user-defined value type that is implemented by the
+    /// user-defined operator function `id`, and returns its result type.
+    fn check_user_defined_operator(
+        &mut self,
+        expr: &hir::Expr<'_>,
+        id: hir::FunctionId,
+        ty: Ty<'gcx>,
+        rhs: Option<(&hir::Expr<'_>, Ty<'gcx>)>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        self.record_res(expr, Res::Item(id.into()));
+        if let Some((rhs, rhs_ty)) = rhs {
+            if rhs_ty.has_error().is_ok() {
+                self.coerce(rhs, rhs_ty, ty);
+            }
+        }
+        match *gcx.type_of_item(id.into()).returns().unwrap() {
+            [ret] => ret,
+            _ => {
+                let msg = "user-defined operator must return exactly one value";
+                let note = "the operator is implemented by this function";
+                let diag = gcx.dcx().err(msg).span(expr.span).span_note(gcx.item_span(id), note);
+                gcx.mk_ty_err(diag.emit())
+            }
+        }
+    }
+
+    fn check_binop(
+        &mut self,
+        span: Span,
+        lhs: &hir::Expr<'_>,
+        lhs_ty: Ty<'gcx>,
+        op: hir::BinOp,
+        rhs: &hir::Expr<'_>,
+        rhs_ty: Ty<'gcx>,
+    ) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        if let Err(guar) = lhs_ty.has_error().and(rhs_ty.has_error()) {
+            return gcx.mk_ty_err(guar);
+        }
+        match self.binop_result(lhs, lhs_ty, op.kind, rhs, rhs_ty) {
+            Ok(ty) => ty,
+            Err(Some(guar)) => gcx.mk_ty_err(guar),
+            Err(None) => {
+                let msg = format!(
+                    "cannot apply binary operator `{}` to types `{}` and `{}`",
+                    op.kind.to_str(),
+                    lhs_ty.display(gcx),
+                    rhs_ty.display(gcx),
+                );
+                gcx.mk_ty_err(gcx.dcx().err(msg).span(span).emit())
+            }
+        }
+    }
+
+    /// Returns the result type of a binary operation.
+    ///
+    /// Returns `Err(None)` if the operator cannot be applied to the given types.
+    fn binop_result(
+        &mut self,
+        lhs: &hir::Expr<'_>,
+        lhs_ty: Ty<'gcx>,
+        op: BinOpKind,
+        rhs: &hir::Expr<'_>,
+        rhs_ty: Ty<'gcx>,
+    ) -> Result<Ty<'gcx>, Option<ErrorGuaranteed>> {
+        use BinOpKind::*;
+        let gcx = self.gcx;
+
+        if is_literal(lhs_ty) && is_literal(rhs_ty) {
+            if is_comparison(op) {
+                return Ok(gcx.types.bool);
+            }
+            let (Some(l), Some(r)) = (self.literal_value(lhs), self.literal_value(rhs)) else {
+                return Err(None);
+            };
+            if matches!(op, Div | Rem) && r.is_zero() {
+                let msg = "division by zero";
+                return Err(Some(gcx.dcx().err(msg).span(rhs.span).emit()));
+            }
+            return fold_rationals(&l, op, &r).map(|v| self.mk_literal_ty(&v)).map_err(|_| None);
+        }
+
+        match op {
+            And | Or => {
+                let bool = gcx.types.bool;
+                if lhs_ty.peel_refs() == bool && rhs_ty.peel_refs() == bool {
+                    Ok(bool)
+                } else {
+                    Err(None)
+                }
+            }
+            Shl | Shr | Sar | Pow => {
+                let lhs_ty = self.mobile_type(lhs_ty).ok_or(None)?;
+                let rhs_valid = match rhs_ty.kind {
+                    TyKind::IntLiteral(negative, _) => !negative,
+                    TyKind::Elementary(ElementaryType::UInt(_)) => true,
+                    _ => false,
+                };
+                let lhs_valid = match lhs_ty.kind {
+                    TyKind::Elementary(ElementaryType::Int(_) | ElementaryType::UInt(_)) => true,
+                    TyKind::Elementary(ElementaryType::FixedBytes(_)) => op != Pow,
+                    _ => false,
+                };
+                if op == Sar || !lhs_valid || !rhs_valid {
+                    return Err(None);
+                }
+                Ok(lhs_ty)
+            }
+            _ => {
+                let common = self.common_type(lhs_ty, rhs_ty).ok_or(None)?;
+                let common = common.peel_refs();
+                let valid = match common.kind {
+                    TyKind::Elementary(ElementaryType::Int(_) | ElementaryType::UInt(_)) => true,
+                    TyKind::Elementary(ElementaryType::FixedBytes(_)) => {
+                        is_comparison(op) || matches!(op, BitAnd | BitOr | BitXor)
+                    }
+                    TyKind::Elementary(ElementaryType::Address(_)) | TyKind::Enum(_) => {
+                        is_comparison(op)
+                    }
+                    TyKind::Elementary(ElementaryType::Bool) | TyKind::Contract(_) => {
+                        matches!(op, Eq | Ne)
+                    }
+                    TyKind::FnPtr(f) => matches!(op, Eq | Ne) && f.is_external(),
+                    _ => false,
+                };
+                if !valid {
+                    return Err(None);
+                }
+                Ok(if is_comparison(op) { gcx.types.bool } else { common })
+            }
+        }
+    }
+
+    /// Checks that `ty`, the type of `expr`, can be implicitly converted to `expected`, emitting an
+    /// error if it cannot.
+    fn coerce(&mut self, expr: &hir::Expr<'_>, ty: Ty<'gcx>, expected: Ty<'gcx>) {
+        if ty.is_implicitly_convertible_to(self.gcx, expected)
+            || self.is_hex_literal_of(expr, expected)
+        {
+            return;
+        }
+
+        // Point at the individual components of tuple expressions.
+        if let (ExprKind::Tuple(exprs), TyKind::Tuple(tys), TyKind::Tuple(expected_tys)) =
+            (&expr.peel_parens().kind, &ty.kind, &expected.kind)
+        {
+            if exprs.len() == expected_tys.len() {
+                for ((expr, &ty), &expected) in exprs.iter().zip(*tys).zip(*expected_tys) {
+                    if let Some(expr) = expr {
+                        self.coerce(expr, ty, expected);
+                    }
+                }
+                return;
+            }
+        }
+
+        self.report_mismatch(expr.span, ty, expected);
+    }
+
+    /// Checks that `rhs_ty`, the type of `rhs`, can be assigned to `lhs`.
+    ///
+    /// Assigning to storage copies the value, unless the target is a local storage pointer, so
+    /// any data location is accepted.
+    fn coerce_assign(
+        &mut self,
+        lhs: &hir::Expr<'_>,
+        rhs: &hir::Expr<'_>,
+        rhs_ty: Ty<'gcx>,
+        lhs_ty: Ty<'gcx>,
+    ) {
+        let gcx = self.gcx;
+        if rhs_ty.has_error().is_err() {
+            return;
+        }
+
+        // Destructuring assignment: check each component individually.
+        if let (ExprKind::Tuple(lhs_exprs), TyKind::Tuple(lhs_tys)) =
+            (&lhs.peel_parens().kind, &lhs_ty.kind)
+        {
+            let rhs_tys = match rhs_ty.kind {
+                TyKind::Tuple(tys) => tys,
+                _ => std::slice::from_ref(&rhs_ty),
+            };
+            if lhs_exprs.len() != rhs_tys.len() {
+                let msg = format!(
+                    "different number of components on the left hand side ({}) than on the right hand side ({})",
+                    lhs_exprs.len(),
+                    rhs_tys.len(),
+                );
+                gcx.dcx().err(msg).span(rhs.span).emit();
+                return;
+            }
+            let rhs_exprs = match rhs.peel_parens().kind {
+                ExprKind::Tuple(exprs) if exprs.len() == rhs_tys.len() => Some(exprs),
+                _ => None,
+            };
+            let components = lhs_exprs.iter().zip(lhs_tys.iter().zip(rhs_tys));
+            for (i, (lhs, (&lhs_ty, &rhs_ty))) in components.enumerate() {
+                let Some(lhs) = lhs else { continue };
+                match rhs_exprs.and_then(|exprs| exprs[i]) {
+                    Some(rhs) => self.coerce_assign(lhs, rhs, rhs_ty, lhs_ty),
+                    None => {
+                        let target = self.assignment_target(lhs, lhs_ty);
+                        if !rhs_ty.is_implicitly_convertible_to(gcx, target) {
+                            self.report_mismatch(rhs.span, rhs_ty, lhs_ty);
+                        }
+                    }
+                }
+            }
+            return;
+        }
+
+        let target = self.assignment_target(lhs, lhs_ty);
+        if rhs_ty.is_implicitly_convertible_to(gcx, target) || self.is_hex_literal_of(rhs, target) {
+            return;
+        }
+        self.coerce(rhs, rhs_ty, lhs_ty);
+    }
+
+    /// Returns the type that values assigned to `lhs` must be convertible to, replacing storage
+    /// references that are copied to with memory references.
+    fn assignment_target(&self, lhs: &hir::Expr<'_>, ty: Ty<'gcx>) -> Ty<'gcx> {
+        let gcx = self.gcx;
+        match (&lhs.peel_parens().kind, &ty.kind) {
+            (ExprKind::Tuple(exprs), TyKind::Tuple(tys)) if exprs.len() == tys.len() => {
+                let tys = exprs
+                    .iter()
+                    .zip(*tys)
+                    .map(|(expr, &ty)| expr.map_or(ty, |expr| self.assignment_target(expr, ty)));
+                gcx.mk_ty(TyKind::Tuple(gcx.mk_ty_iter(tys)))
+            }
+            _ if ty.is_ref_at(DataLocation::Storage) && !self.is_local_variable(lhs) => {
+                ty.with_loc(gcx, DataLocation::Memory)
+            }
+            _ => ty,
+        }
+    }
+
+    /// Checks that `expr` can be assigned to, deleted, incremented or decremented.
+    ///
+    /// Tuples are only valid l-values in assignments. Returns `false` if an error was emitted.
+    fn check_lvalue(&self, expr: &hir::Expr<'_>, is_assignment: bool) -> bool {
+        let gcx = self.gcx;
+        let expr = expr.peel_parens();
+        if gcx.type_of_expr_opt(expr.id).is_none_or(|ty| ty.has_error().is_err()) {
+            return false;
+        }
+        let err = |msg: &str| {
+            gcx.dcx().err(msg.to_string()).span(expr.span).emit();
+            false
+        };
+        match expr.kind {
+            ExprKind::Tuple(exprs) if is_assignment => {
+                let mut ok = true;
+                for expr in exprs.iter().flatten() {
+                    ok &= self.check_lvalue(expr, is_assignment);
+                }
+                return ok;
+            }
+            ExprKind::Ident(_) => match gcx.res_of_expr(expr.id) {
+                Some(Res::Item(hir::ItemId::Variable(id))) => {
+                    if !self.check_variable_write(expr.span, id) {
+                        return false;
+                    }
+                }
+                _ => return err("expression has to be an lvalue"),
+            },
+            ExprKind::Member(base, _) => {
+                if self.check_calldata_write(expr, base) {
+                    return false;
+                }
+                match gcx.member_of_expr(expr.id).and_then(|m| m.res) {
+                    Some(Res::Item(hir::ItemId::Variable(id))) => {
+                        if !self.check_variable_write(expr.span, id) {
+                            return false;
+                        }
+                    }
+                    Some(Res::Builtin(Builtin::ArrayLength)) => {
+                        return err(
+                            "member `length` is read-only and cannot be used to resize arrays",
+                        );
+                    }
+                    _ => return err("expression has to be an lvalue"),
+                }
+            }
+            ExprKind::Index(base, _) => {
+                if self.check_calldata_write(expr, base) {
+                    return false;
+                }
+                let base_ty = gcx.type_of_expr(base.id);
+                if let TyKind::Elementary(ElementaryType::FixedBytes(_)) = base_ty.kind {
+                    return err("single bytes in fixed bytes arrays cannot be modified");
+                }
+            }
+            // `push()` returns a reference to the new element.
+            ExprKind::Call(callee, ref args, _)
+                if args.is_empty()
+                    && matches!(callee.peel_parens().kind, ExprKind::Member(_, m) if m.name == sym::push) =>
+                {}
+            _ => return err("expression has to be an lvalue"),
+        }
+        if is_assignment && gcx.type_of_expr(expr.id).has_mapping() && !self.is_local_variable(expr)
+        {
+            return err("types in storage containing (nested) mappings cannot be assigned to");
+        }
+        true
+    }
+
+    /// Checks that the variable `id` can be written to in the current context, emitting an error
+    /// and returning `false` if it cannot.
+    fn check_variable_write(&self, span: Span, id: hir::VariableId) -> bool {
+        let gcx = self.gcx;
+        let var = gcx.hir.variable(id);
+        if var.is_constant() {
+            gcx.dcx().err("cannot assign to a constant variable").span(span).emit();
+            return false;
+        }
+        if var.is_immutable() {
+            let in_constructor = self.function.is_some_and(|f| {
+                let f = gcx.hir.function(f);
+                f.kind.is_constructor() && f.contract == var.contract
+            });
+            if !in_constructor {
+                gcx.dcx()
+                    .err("cannot write to immutable here")
+                    .span(span)
+                    .help("immutable variables can only be initialized inline or assigned directly in the constructor")
+                    .emit();
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Checks that `expr`, a member or index access on `base`, does not modify calldata, emitting
+    /// an error and returning `true` if it does.
+    fn check_calldata_write(&self, expr: &hir::Expr<'_>, base: &hir::Expr<'_>) -> bool {
+        let TyKind::Ref(inner, DataLocation::Calldata) = self.gcx.type_of_expr(base.id).kind else {
+            return false;
+        };
+        let msg = if matches!(inner.kind, TyKind::Struct(_)) {
+            "calldata structs are read-only"
+        } else {
+            "calldata arrays are read-only"
+        };
+        self.gcx.dcx().err(msg).span(expr.span).emit();
+        true
+    }
+
+    /// Returns `true` if `expr` refers to a local or return variable.
+    fn is_local_variable(&self, expr: &hir::Expr<'_>) -> bool {
+        let expr = expr.peel_parens();
+        matches!(expr.kind, ExprKind::Ident(_))
+            && matches!(
+                self.gcx.res_of_expr(expr.id),
+                Some(Res::Item(hir::ItemId::Variable(id)))
+                    if self.gcx.hir.variable(id).is_local_or_return()
+            )
+    }
+
+    /// Returns `true` if `expr` is a hexadecimal number literal with exactly as many digits as the
+    /// fixed-bytes type `ty`, as in `bytes2 x = 0x0012;`.
+    fn is_hex_literal_of(&self, expr: &hir::Expr<'_>, ty: Ty<'gcx>) -> bool {
+        let (ExprKind::Lit(lit), &TyKind::Elementary(ElementaryType::FixedBytes(size))) =
+            (&expr.peel_parens().kind, &ty.kind)
+        else {
+            return false;
+        };
+        let s = lit.symbol.as_str();
+        matches!(lit.kind, LitKind::Number(_))
+            && s.starts_with("0x")
+            && s.len() - 2 == size.bytes() as usize * 2
+            && !s.contains('_')
+    }
+
+    fn report_mismatch(&self, span: Span, ty: Ty<'gcx>, expected: Ty<'gcx>) -> ErrorGuaranteed {
+        let msg = format!(
+            "type `{}` is not implicitly convertible to expected type `{}`",
+            ty.display(self.gcx),
+            expected.display(self.gcx),
+        );
+        self.gcx.dcx().err(msg).span(span).emit()
+    }
+
+    /// Returns the type that both `a` and `b` can be implicitly converted to, if any.
+    fn common_type(&self, a: Ty<'gcx>, b: Ty<'gcx>) -> Option<Ty<'gcx>> {
+        let (a, b) = match (is_literal(a), is_literal(b)) {
+            (true, true) => (self.mobile_type(a)?, self.mobile_type(b)?),
+            _ => (a, b),
+        };
+        if b.is_implicitly_convertible_to(self.gcx, a) {
+            self.mobile_type(a)
+        } else if a.is_implicitly_convertible_to(self.gcx, b) {
+            self.mobile_type(b)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest non-literal type that `ty` can be implicitly converted to.
+    ///
+    /// Returns `None` if `ty` is a literal that cannot be represented by any other type.
+    fn mobile_type(&self, ty: Ty<'gcx>) -> Option<Ty<'gcx>> {
+        let gcx = self.gcx;
+        match ty.kind {
+            TyKind::IntLiteral(negative, bits) => {
+                let bits = if negative { bits + 1 } else { bits };
+                if bits > 256 {
+                    return None;
+                }
+                let bits = bits.max(1).div_ceil(8) * 8;
+                Some(if negative { gcx.types.int(bits) } else { gcx.types.uint(bits) })
+            }
+            // TODO: Fixed point types.
+            TyKind::RationalLiteral(_) => None,
+            TyKind::StringLiteral(..) => Some(gcx.types.string_ref.memory),
+            _ => Some(ty),
+        }
+    }
+
+    fn mk_literal_ty(&self, value: &BigRational) -> Ty<'gcx> {
+        if !value.is_integer() {
+            return self.gcx.mk_ty(TyKind::RationalLiteral(value.is_negative()));
+        }
+        let value = value.to_integer();
+        let negative = value.is_negative();
+        let bits = if negative { (-value - 1u8).bits() } else { value.bits() };
+        self.gcx.mk_ty_int_literal(negative, bits.try_into().unwrap_or(u16::MAX))
+    }
+
+    /// Evaluates a constant expression made only of number literals.
+    fn literal_value(&self, expr: &hir::Expr<'_>) -> Option<BigRational> {
+        let expr = expr.peel_parens();
+        match expr.kind {
+            ExprKind::Lit(lit) => match lit.kind {
+                LitKind::Number(ref n) => Some(BigRational::from_integer(n.clone())),
+                LitKind::Rational(ref r) => Some(r.clone()),
+                _ => None,
+            },
+            ExprKind::Unary(op, inner) => {
+                let v = self.literal_value(inner)?;
+                match op.kind {
+                    UnOpKind::Neg => Some(-v),
+                    UnOpKind::BitNot if v.is_integer() => Some(-v - BigRational::one()),
+                    _ => None,
+                }
+            }
+            ExprKind::Binary(lhs, op, rhs) => {
+                fold_rationals(&self.literal_value(lhs)?, op.kind, &self.literal_value(rhs)?).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_literal(ty: Ty<'_>) -> bool {
+    matches!(ty.kind, TyKind::IntLiteral(..) | TyKind::RationalLiteral(_))
+}
+
+pub(super) fn is_comparison(op: BinOpKind) -> bool {
+    use BinOpKind::*;
+    matches!(op, Lt | Le | Gt | Ge | Eq | Ne)
+}
+
+/// Returns the name in `candidates` that is most similar to `name`, if any is close enough.
+fn find_similar_name(candidates: impl Iterator<Item = Symbol>, name: Symbol) -> Option<Symbol> {
+    let name = name.as_str();
+    let max_dist = (name.len() / 3).max(1);
+    candidates
+        .filter_map(|c| {
+            let dist = strsim::levenshtein(c.as_str(), name);
+            (dist <= max_dist && dist < name.len()).then_some((dist, c))
+        })
+        .min_by_key(|&(dist, _)| dist)
+        .map(|(_, c)| c)
+}
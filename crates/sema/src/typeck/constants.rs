@@ -0,0 +1,131 @@
+//! Constant variable checks.
+//!
+//! Checks that the initializers of constant variables are compile-time constants, and evaluates
+//! them where possible to report arithmetic errors, such as overflows of the declared type.
+
+use crate::{
+    builtins::Builtin,
+    eval::{ConstantEvaluator, EvalErrorKind},
+    hir::{self, ExprKind, Res, UnOpKind},
+    ty::{Gcx, TyKind},
+};
+use solar_ast::StateMutability;
+
+pub(super) fn check_variable(gcx: Gcx<'_>, id: hir::VariableId) {
+    let var = gcx.hir.variable(id);
+    if !var.is_constant() {
+        return;
+    }
+    // Missing initializers are reported in AST validation.
+    let Some(init) = var.initializer else { return };
+    if gcx.type_of_expr_opt(init.id).is_none_or(|ty| ty.has_error().is_err()) {
+        return;
+    }
+
+    if !is_compile_time_constant(gcx, init) {
+        let msg = "initial value for constant variable has to be compile-time constant";
+        gcx.dcx().err(msg).span(init.span).emit();
+        return;
+    }
+
+    // Literals are already checked to fit the declared type during type checking.
+    if let TyKind::IntLiteral(..) | TyKind::RationalLiteral(..) = gcx.type_of_expr(init.id).kind {
+        return;
+    }
+    let mut evaluator = ConstantEvaluator::new(gcx);
+    if let Err(err) = evaluator.try_eval_variable(id) {
+        if let EvalErrorKind::ArithmeticOverflow
+        | EvalErrorKind::BytesTooLong
+        | EvalErrorKind::DivisionByZero
+        | EvalErrorKind::RecursionLimitReached = err.kind
+        {
+            evaluator.emit_eval_error(init, err);
+        }
+    }
+}
+
+/// Returns `true` if the given type checked expression can be evaluated at compile time.
+///
+/// This is solc's `isPure` annotation: literals, constant variables, type conversions and calls
+/// to pure builtin functions, and operations on such expressions.
+fn is_compile_time_constant(gcx: Gcx<'_>, expr: &hir::Expr<'_>) -> bool {
+    let is_constant = |expr: &hir::Expr<'_>| is_compile_time_constant(gcx, expr);
+    match expr.kind {
+        ExprKind::Lit(_) | ExprKind::Type(_) | ExprKind::TypeCall(_) | ExprKind::Err(_) => true,
+        ExprKind::Ident(_) => match gcx.res_of_expr(expr.id) {
+            Some(Res::Item(hir::ItemId::Variable(id))) => gcx.hir.variable(id).is_constant(),
+            Some(Res::Item(hir::ItemId::Function(_))) => false,
+            Some(Res::Builtin(Builtin::This | Builtin::Super)) => false,
+            _ => true,
+        },
+        ExprKind::Member(base, _) => match gcx.member_of_expr(expr.id).and_then(|m| m.res) {
+            Some(Res::Item(hir::ItemId::Variable(id))) => gcx.hir.variable(id).is_constant(),
+            Some(Res::Item(hir::ItemId::Function(_))) => false,
+            Some(Res::Builtin(builtin)) => !reads_environment(builtin) && is_constant(base),
+            _ => is_constant(base),
+        },
+        ExprKind::Call(callee, ref args, options) => {
+            let is_pure_callee = match gcx.type_of_expr(callee.id).kind {
+                // Type conversions.
+                TyKind::Type(_) => true,
+                _ => {
+                    let res = match callee.peel_parens().kind {
+                        ExprKind::Ident(_) => gcx.res_of_expr(callee.peel_parens().id),
+                        ExprKind::Member(..) => {
+                            gcx.member_of_expr(callee.peel_parens().id).and_then(|m| m.res)
+                        }
+                        _ => None,
+                    };
+                    matches!(res, Some(Res::Builtin(_)))
+                        && gcx.type_of_expr(callee.id).state_mutability()
+                            == Some(StateMutability::Pure)
+                        && is_constant(callee)
+                }
+            };
+            is_pure_callee && options.is_none() && args.exprs().all(is_constant)
+        }
+        ExprKind::Unary(op, expr) => {
+            !matches!(
+                op.kind,
+                UnOpKind::PreInc | UnOpKind::PreDec | UnOpKind::PostInc | UnOpKind::PostDec
+            ) && is_constant(expr)
+        }
+        ExprKind::Binary(lhs, _, rhs) => is_constant(lhs) && is_constant(rhs),
+        ExprKind::Ternary(cond, true_, false_) => {
+            is_constant(cond) && is_constant(true_) && is_constant(false_)
+        }
+        ExprKind::Tuple(exprs) => exprs.iter().flatten().all(|expr| is_constant(expr)),
+        ExprKind::Array(exprs) => exprs.iter().all(is_constant),
+        ExprKind::Index(base, index) => is_constant(base) && index.is_none_or(is_constant),
+        ExprKind::Payable(expr) => is_constant(expr),
+        ExprKind::Assign(..) | ExprKind::Delete(_) | ExprKind::New(_) | ExprKind::Slice(..) => {
+            false
+        }
+    }
+}
+
+/// Returns `true` if the builtin reads the execution environment, like `block.timestamp`.
+fn reads_environment(builtin: Builtin) -> bool {
+    matches!(
+        builtin,
+        Builtin::BlockCoinbase
+            | Builtin::BlockTimestamp
+            | Builtin::BlockDifficulty
+            | Builtin::BlockPrevrandao
+            | Builtin::BlockNumber
+            | Builtin::BlockGaslimit
+            | Builtin::BlockChainid
+            | Builtin::BlockBasefee
+            | Builtin::BlockBlobbasefee
+            | Builtin::MsgSender
+            | Builtin::MsgGas
+            | Builtin::MsgValue
+            | Builtin::MsgData
+            | Builtin::MsgSig
+            | Builtin::TxOrigin
+            | Builtin::TxGasPrice
+            | Builtin::AddressBalance
+            | Builtin::AddressCode
+            | Builtin::AddressCodehash
+    )
+}
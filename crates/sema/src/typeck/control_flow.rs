@@ -0,0 +1,402 @@
+//! Control flow analysis.
+//!
+//! Reports unreachable code, unnamed return variables that can remain unassigned, and storage or
+//! calldata pointers that can be accessed before they are assigned.
+//!
+//! As in solc, named return variables that can remain unassigned are not reported unless they are
+//! pointers. Like unnamed ones they are zero-initialized, and naming them signals the intent to
+//! return the default value on the paths that do not assign them.
+//!
+//! Similarly, only pointer variables are checked for accesses before assignment. Other local
+//! variables, including memory structs and arrays, are zero-initialized when declared, so reading
+//! them before an assignment is well-defined and not reported.
+//!
+//! Reference: <https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/analysis/ControlFlowAnalyzer.cpp>
+
+use crate::{
+    builtins::Builtin,
+    hir::{self, ExprKind, LoopSource, Res, StmtKind, Visit},
+    ty::{Gcx, Ty, TyKind},
+};
+use solar_ast::{DataLocation, LitKind};
+use solar_data_structures::{map::FxHashSet, smallvec::SmallVec, Never};
+use std::ops::ControlFlow;
+
+/// Analyzes the control flow of the body of the given function or modifier.
+pub(super) fn check_function(gcx: Gcx<'_>, id: hir::FunctionId) {
+    let f = gcx.hir.function(id);
+    if f.is_getter() {
+        return;
+    }
+    let Some(body) = f.body else { return };
+
+    let mut analyzer = ControlFlowAnalyzer::new(gcx, f.returns);
+    let entry = State { unassigned: f.returns.iter().map(|_| true).collect() };
+    let end = analyzer.check_block(body, Some(entry));
+    analyzer.exit(end);
+
+    let Some(exit) = analyzer.exit else { return };
+    for (i, &ret) in f.returns.iter().enumerate() {
+        if !exit.is_unassigned(i) {
+            continue;
+        }
+        let var = gcx.hir.variable(ret);
+        if let Some(loc) = pointer_location(gcx.type_of_item(ret.into())) {
+            let msg = format!(
+                "this variable is of {loc} pointer type and can be returned without prior \
+                 assignment, which would lead to undefined behaviour"
+            );
+            gcx.dcx().err(msg).span(var.span).emit();
+        } else if var.name.is_none() {
+            gcx.dcx()
+                .warn("unnamed return variable can remain unassigned")
+                .span(var.span)
+                .help(
+                    "add an explicit return with value to all non-reverting code paths \
+                     or name the variable",
+                )
+                .emit();
+        }
+    }
+}
+
+/// Returns the data location of the given type if it is a storage or calldata pointer.
+fn pointer_location(ty: Ty<'_>) -> Option<DataLocation> {
+    match ty.kind {
+        TyKind::Ref(_, loc @ (DataLocation::Storage | DataLocation::Calldata)) => Some(loc),
+        TyKind::Mapping(..) => Some(DataLocation::Storage),
+        _ => None,
+    }
+}
+
+/// The state of the analysis at a reachable point in the function body.
+#[derive(Clone)]
+struct State {
+    /// Whether each tracked variable may not have been assigned yet.
+    ///
+    /// Indexed by the position of the variable in [`ControlFlowAnalyzer::tracked`]. Variables
+    /// that are tracked after this state was created are missing, and are not in scope.
+    unassigned: SmallVec<[bool; 8]>,
+}
+
+impl State {
+    fn join(a: Option<Self>, b: Option<Self>) -> Option<Self> {
+        match (a, b) {
+            (Some(mut a), Some(b)) => {
+                if a.unassigned.len() < b.unassigned.len() {
+                    a.unassigned.resize(b.unassigned.len(), false);
+                }
+                for (a, b) in a.unassigned.iter_mut().zip(b.unassigned) {
+                    *a |= b;
+                }
+                Some(a)
+            }
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    fn is_unassigned(&self, i: usize) -> bool {
+        self.unassigned.get(i).copied().unwrap_or(false)
+    }
+
+    fn set_unassigned(&mut self, i: usize, unassigned: bool) {
+        if i >= self.unassigned.len() {
+            self.unassigned.resize(i + 1, false);
+        }
+        self.unassigned[i] = unassigned;
+    }
+
+    fn assign_all(&mut self) {
+        self.unassigned.fill(false);
+    }
+}
+
+struct ControlFlowAnalyzer<'gcx> {
+    gcx: Gcx<'gcx>,
+    /// The variables whose assignments are tracked: the return variables, followed by the local
+    /// calldata pointers that are declared without an initializer.
+    tracked: Vec<hir::VariableId>,
+    /// The variables that were already reported as accessed before being assigned.
+    reported: FxHashSet<hir::VariableId>,
+    /// The join of the states at the `break` statements of the innermost loop.
+    breaks: Option<State>,
+    /// The join of the states at the `continue` statements of the innermost loop.
+    continues: Option<State>,
+    /// The join of the states at which the function returns without a value.
+    exit: Option<State>,
+}
+
+impl<'gcx> ControlFlowAnalyzer<'gcx> {
+    fn new(gcx: Gcx<'gcx>, returns: &'gcx [hir::VariableId]) -> Self {
+        Self {
+            gcx,
+            tracked: returns.to_vec(),
+            reported: FxHashSet::default(),
+            breaks: None,
+            continues: None,
+            exit: None,
+        }
+    }
+
+    fn exit(&mut self, state: Option<State>) {
+        self.exit = State::join(self.exit.take(), state);
+    }
+
+    /// Analyzes the statements of a block, warning about the first unreachable statement.
+    fn check_block(&mut self, block: hir::Block<'gcx>, mut state: Option<State>) -> Option<State> {
+        for stmt in block {
+            if state.is_none() {
+                let span = stmt.span.to(block.last().unwrap().span);
+                self.gcx.dcx().warn("unreachable code").span(span).emit();
+                return None;
+            }
+            state = self.check_stmt(stmt, state);
+        }
+        state
+    }
+
+    fn check_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>, state: Option<State>) -> Option<State> {
+        let mut state = state?;
+        match stmt.kind {
+            StmtKind::DeclSingle(id) => {
+                if let Some(init) = self.gcx.hir.variable(id).initializer {
+                    self.check_expr(init, &mut state);
+                } else if pointer_location(self.gcx.type_of_item(id.into()))
+                    == Some(DataLocation::Calldata)
+                {
+                    // Uninitialized storage pointers are rejected by the type checker.
+                    state.set_unassigned(self.tracked.len(), true);
+                    self.tracked.push(id);
+                }
+            }
+            StmtKind::DeclMulti(_, expr) | StmtKind::Emit(expr) => {
+                self.check_expr(expr, &mut state);
+            }
+            StmtKind::Expr(expr) => {
+                self.check_expr(expr, &mut state);
+                if self.is_revert_call(expr) {
+                    return None;
+                }
+            }
+            StmtKind::Revert(expr) => {
+                self.check_expr(expr, &mut state);
+                return None;
+            }
+            StmtKind::Return(expr) => {
+                if let Some(expr) = expr {
+                    self.check_expr(expr, &mut state);
+                    state.assign_all();
+                }
+                self.exit(Some(state));
+                return None;
+            }
+            StmtKind::Break => {
+                self.breaks = State::join(self.breaks.take(), Some(state));
+                return None;
+            }
+            StmtKind::Continue => {
+                self.continues = State::join(self.continues.take(), Some(state));
+                return None;
+            }
+            StmtKind::Block(block) | StmtKind::UncheckedBlock(block) => {
+                return self.check_block(block, Some(state));
+            }
+            StmtKind::Loop(block, source) => return self.check_loop(block, source, state),
+            StmtKind::If(cond, then, else_) => {
+                self.check_expr(cond, &mut state);
+                let then = self.check_stmt(then, Some(state.clone()));
+                let else_ = match else_ {
+                    Some(else_) => self.check_stmt(else_, Some(state)),
+                    None => Some(state),
+                };
+                return State::join(then, else_);
+            }
+            StmtKind::Try(try_) => {
+                self.check_expr(&try_.expr, &mut state);
+                let mut end = None;
+                for clause in try_.clauses {
+                    let clause_end = self.check_block(clause.block, Some(state.clone()));
+                    end = State::join(end, clause_end);
+                }
+                return end;
+            }
+            StmtKind::Placeholder => {}
+            // Inline assembly may assign to the return variables.
+            StmtKind::Err(_) => state.assign_all(),
+        }
+        Some(state)
+    }
+
+    /// Analyzes a loop, using its source to recover the structure it was desugared from in
+    /// `lower_loop_stmt`.
+    fn check_loop(
+        &mut self,
+        block: hir::Block<'gcx>,
+        source: LoopSource,
+        mut state: State,
+    ) -> Option<State> {
+        let outer_breaks = self.breaks.take();
+        let outer_continues = self.continues.take();
+
+        let end = match (source, block) {
+            // loop { if (<cond>) <body> else break; }
+            (LoopSource::While, [stmt]) => match stmt.kind {
+                StmtKind::If(cond, body, Some(_)) => {
+                    self.check_expr(cond, &mut state);
+                    let after_cond = (!is_true(cond)).then(|| state.clone());
+                    self.check_stmt(body, Some(state));
+                    after_cond
+                }
+                _ => self.check_block(block, Some(state)),
+            },
+            // loop { { <body> } if (<cond>) continue else break; }
+            (LoopSource::DoWhile, [body, check]) => match check.kind {
+                StmtKind::If(cond, _, Some(_)) => {
+                    let end = self.check_stmt(body, Some(state));
+                    let end = State::join(end, self.continues.take());
+                    end.and_then(|mut state| {
+                        self.check_expr(cond, &mut state);
+                        (!is_true(cond)).then_some(state)
+                    })
+                }
+                _ => self.check_block(block, Some(state)),
+            },
+            // loop { if (<cond>) { { <body> } <next>; } else break; }
+            (LoopSource::For { has_next }, [stmt]) => {
+                let (cond, inner) = match stmt.kind {
+                    StmtKind::If(cond, inner, Some(hir::Stmt { kind: StmtKind::Break, .. })) => {
+                        (Some(cond), inner)
+                    }
+                    _ => (None, stmt),
+                };
+                let after_cond = cond.and_then(|cond| {
+                    self.check_expr(cond, &mut state);
+                    (!is_true(cond)).then(|| state.clone())
+                });
+                match inner.kind {
+                    StmtKind::Block(
+                        [body, next @ hir::Stmt { kind: StmtKind::Expr(next_expr), .. }],
+                    ) if has_next => {
+                        let end = self.check_stmt(body, Some(state));
+                        if let Some(mut state) = State::join(end, self.continues.take()) {
+                            self.check_expr(next_expr, &mut state);
+                        } else {
+                            self.gcx.dcx().warn("unreachable code").span(next.span).emit();
+                        }
+                    }
+                    _ => {
+                        self.check_stmt(inner, Some(state));
+                    }
+                }
+                after_cond
+            }
+            _ => self.check_block(block, Some(state)),
+        };
+
+        let end = State::join(end, self.breaks.take());
+        self.breaks = outer_breaks;
+        self.continues = outer_continues;
+        end
+    }
+
+    /// Marks the tracked variables assigned in the given expression, and reports the ones that
+    /// are accessed before being assigned.
+    fn check_expr(&mut self, expr: &'gcx hir::Expr<'gcx>, state: &mut State) {
+        let _ = AssignmentVisitor { analyzer: self, state }.visit_expr(expr);
+    }
+
+    fn check_read(&mut self, expr: &hir::Expr<'_>, id: hir::VariableId, state: &State) {
+        let Some(i) = self.tracked.iter().position(|&v| v == id) else { return };
+        if !state.is_unassigned(i) {
+            return;
+        }
+        let Some(loc) = pointer_location(self.gcx.type_of_item(id.into())) else { return };
+        if !self.reported.insert(id) {
+            return;
+        }
+        let msg = format!(
+            "this variable is of {loc} pointer type and can be accessed without prior \
+             assignment, which would lead to undefined behaviour"
+        );
+        let var = self.gcx.hir.variable(id);
+        self.gcx
+            .dcx()
+            .err(msg)
+            .span(expr.span)
+            .span_note(var.span, "variable declared here")
+            .emit();
+    }
+
+    /// Returns `true` if `expr` is a call to `revert`.
+    fn is_revert_call(&self, expr: &hir::Expr<'_>) -> bool {
+        let ExprKind::Call(callee, ..) = expr.kind else { return false };
+        matches!(
+            self.gcx.res_of_expr(callee.peel_parens().id),
+            Some(Res::Builtin(Builtin::Revert | Builtin::RevertMsg))
+        )
+    }
+}
+
+/// Returns `true` if `expr` is the `true` literal.
+fn is_true(expr: &hir::Expr<'_>) -> bool {
+    matches!(expr.peel_parens().kind, ExprKind::Lit(lit) if matches!(lit.kind, LitKind::Bool(true)))
+}
+
+struct AssignmentVisitor<'a, 'gcx> {
+    analyzer: &'a mut ControlFlowAnalyzer<'gcx>,
+    state: &'a mut State,
+}
+
+impl<'gcx> AssignmentVisitor<'_, 'gcx> {
+    fn var_of(&self, expr: &hir::Expr<'_>) -> Option<hir::VariableId> {
+        match self.analyzer.gcx.res_of_expr(expr.id) {
+            Some(Res::Item(hir::ItemId::Variable(id))) => Some(id),
+            _ => None,
+        }
+    }
+
+    fn visit_lvalue(&mut self, lhs: &'gcx hir::Expr<'gcx>) {
+        match lhs.kind {
+            ExprKind::Tuple(exprs) => {
+                for expr in exprs.iter().flatten() {
+                    self.visit_lvalue(expr);
+                }
+            }
+            ExprKind::Ident(_) => {
+                let Some(id) = self.var_of(lhs) else { return };
+                if let Some(i) = self.analyzer.tracked.iter().position(|&v| v == id) {
+                    self.state.set_unassigned(i, false);
+                }
+            }
+            _ => {
+                let _ = self.visit_expr(lhs);
+            }
+        }
+    }
+}
+
+impl<'gcx> Visit<'gcx> for AssignmentVisitor<'_, 'gcx> {
+    type BreakValue = Never;
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.analyzer.gcx.hir
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            ExprKind::Assign(lhs, None, rhs) => {
+                self.visit_expr(rhs)?;
+                self.visit_lvalue(lhs);
+                ControlFlow::Continue(())
+            }
+            ExprKind::Ident(_) => {
+                if let Some(id) = self.var_of(expr) {
+                    self.analyzer.check_read(expr, id, self.state);
+                }
+                ControlFlow::Continue(())
+            }
+            _ => self.walk_expr(expr),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Immutable state variable initialization checks.
+//!
+//! Immutable variables must be initialized exactly once on every path, either inline or in the
+//! constructor of the contract that declares them, and cannot be read during construction before
+//! they are initialized. Whether they are written from a valid context at all is checked in
+//! [`TypeChecker`](super::checker::TypeChecker).
+//!
+//! Reference: <https://github.com/ethereum/solidity/blob/v0.8.20/libsolidity/analysis/ImmutableValidator.cpp>
+
+use crate::{
+    hir::{self, ExprKind, Res, StmtKind, UnOpKind, Visit},
+    ty::Gcx,
+};
+use solar_data_structures::{
+    map::{FxHashMap, FxHashSet},
+    Never,
+};
+use solar_interface::Span;
+use std::ops::ControlFlow;
+
+pub(super) fn check_contract(gcx: Gcx<'_>, id: hir::ContractId) {
+    let c = gcx.hir.contract(id);
+    if c.kind.is_interface() {
+        return;
+    }
+    let mut validator = ImmutableValidator {
+        gcx,
+        contract: id,
+        initialized: FxHashMap::default(),
+        maybe_initialized: FxHashMap::default(),
+        reported: FxHashSet::default(),
+        loop_depth: 0,
+    };
+
+    // State variable initializers are executed in declaration order, before the constructor body.
+    for var_id in c.variables() {
+        let var = gcx.hir.variable(var_id);
+        if let Some(init) = var.initializer {
+            let _ = validator.visit_expr(init);
+            if var.is_immutable() {
+                validator.initialized.insert(var_id, var.span);
+                validator.maybe_initialized.insert(var_id, var.span);
+            }
+        }
+    }
+    if let Some(ctor) = c.ctor {
+        let _ = validator.visit_nested_function(ctor);
+    }
+
+    for var_id in c.variables() {
+        let var = gcx.hir.variable(var_id);
+        if var.is_immutable() && !validator.initialized.contains_key(&var_id) {
+            let name = var.name.unwrap();
+            let msg = if validator.maybe_initialized.contains_key(&var_id) {
+                format!("immutable variable `{name}` is not initialized on all paths")
+            } else {
+                format!("immutable variable `{name}` is never initialized")
+            };
+            gcx.dcx()
+                .err(msg)
+                .span(var.span)
+                .help("initialize it inline or assign it in the constructor")
+                .emit();
+        }
+    }
+}
+
+/// Walks the construction code of a contract in execution order, tracking which of its immutable
+/// variables have been initialized.
+struct ImmutableValidator<'gcx> {
+    gcx: Gcx<'gcx>,
+    contract: hir::ContractId,
+    /// The immutable variables initialized on every path so far, with the span of their
+    /// initialization.
+    initialized: FxHashMap<hir::VariableId, Span>,
+    /// The immutable variables initialized on at least one path so far.
+    maybe_initialized: FxHashMap<hir::VariableId, Span>,
+    /// The variables for which a read before initialization has already been reported.
+    reported: FxHashSet<hir::VariableId>,
+    loop_depth: usize,
+}
+
+impl<'gcx> ImmutableValidator<'gcx> {
+    /// Returns the immutable variable of the current contract that `expr` refers to, if any.
+    fn immutable_of(&self, expr: &hir::Expr<'_>) -> Option<hir::VariableId> {
+        let res = match expr.peel_parens().kind {
+            ExprKind::Ident(_) => self.gcx.res_of_expr(expr.peel_parens().id),
+            ExprKind::Member(..) => {
+                self.gcx.member_of_expr(expr.peel_parens().id).and_then(|m| m.res)
+            }
+            _ => None,
+        };
+        let Some(Res::Item(hir::ItemId::Variable(id))) = res else { return None };
+        let var = self.gcx.hir.variable(id);
+        (var.is_immutable() && var.contract == Some(self.contract)).then_some(id)
+    }
+
+    fn read(&mut self, id: hir::VariableId, span: Span) {
+        if self.initialized.contains_key(&id) || !self.reported.insert(id) {
+            return;
+        }
+        let name = self.gcx.hir.variable(id).name.unwrap();
+        self.gcx
+            .dcx()
+            .err(format!("immutable variable `{name}` is read before it is initialized"))
+            .span(span)
+            .note("immutable variables cannot be read during construction until they are assigned")
+            .emit();
+    }
+
+    fn write(&mut self, id: hir::VariableId, span: Span) {
+        let dcx = self.gcx.dcx();
+        if self.loop_depth > 0 {
+            dcx.err("immutable variables cannot be initialized inside a loop").span(span).emit();
+        } else if let Some(&prev) = self.maybe_initialized.get(&id) {
+            let name = self.gcx.hir.variable(id).name.unwrap();
+            dcx.err(format!("immutable variable `{name}` is already initialized"))
+                .span(span)
+                .span_note(prev, "previously initialized here")
+                .emit();
+        }
+        self.initialized.entry(id).or_insert(span);
+        self.maybe_initialized.entry(id).or_insert(span);
+    }
+
+    /// Visits the given control flow branches, each starting from the current state.
+    ///
+    /// Afterwards, variables are initialized if they are initialized in all the branches, and may
+    /// be initialized if they may be initialized in any of them.
+    fn visit_branches(
+        &mut self,
+        branches: impl IntoIterator<Item = hir::Block<'gcx>>,
+    ) -> ControlFlow<Never> {
+        let before = self.initialized.clone();
+        let maybe_before = self.maybe_initialized.clone();
+        let mut after: Option<FxHashMap<hir::VariableId, Span>> = None;
+        let mut maybe_after = FxHashMap::default();
+        for block in branches {
+            self.initialized.clone_from(&before);
+            self.maybe_initialized.clone_from(&maybe_before);
+            for stmt in block {
+                self.visit_stmt(stmt)?;
+            }
+            match &mut after {
+                Some(after) => after.retain(|id, _| self.initialized.contains_key(id)),
+                None => after = Some(std::mem::take(&mut self.initialized)),
+            }
+            for (id, span) in self.maybe_initialized.drain() {
+                maybe_after.entry(id).or_insert(span);
+            }
+        }
+        self.initialized = after.unwrap_or(before);
+        self.maybe_initialized = maybe_after;
+        ControlFlow::Continue(())
+    }
+
+    /// Visits the target of an assignment, recording the immutable variables it initializes.
+    fn visit_lvalue(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Never> {
+        if let ExprKind::Tuple(exprs) = expr.peel_parens().kind {
+            return exprs.iter().flatten().try_for_each(|expr| self.visit_lvalue(expr));
+        }
+        match self.immutable_of(expr) {
+            Some(id) => {
+                self.write(id, expr.span);
+                ControlFlow::Continue(())
+            }
+            None => self.visit_expr(expr),
+        }
+    }
+}
+
+impl<'gcx> Visit<'gcx> for ImmutableValidator<'gcx> {
+    type BreakValue = Never;
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            StmtKind::Loop(..) => {
+                let maybe_before = self.maybe_initialized.clone();
+                self.loop_depth += 1;
+                let r = self.walk_stmt(stmt);
+                self.loop_depth -= 1;
+                // Initializations in loops are already reported, so don't report them again as
+                // missing on some paths.
+                for (&id, &span) in &self.maybe_initialized {
+                    if !maybe_before.contains_key(&id) {
+                        self.initialized.entry(id).or_insert(span);
+                    }
+                }
+                r
+            }
+            // Each branch may initialize the variables independently.
+            StmtKind::If(cond, true_, false_) => {
+                self.visit_expr(cond)?;
+                let false_ = false_.map_or(&[][..], std::slice::from_ref);
+                self.visit_branches([std::slice::from_ref(true_), false_])
+            }
+            StmtKind::Try(try_) => {
+                self.visit_expr(&try_.expr)?;
+                self.visit_branches(try_.clauses.iter().map(|clause| clause.block))
+            }
+            _ => self.walk_stmt(stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            ExprKind::Assign(lhs, op, rhs) => {
+                self.visit_expr(rhs)?;
+                if op.is_some() {
+                    self.visit_expr(lhs)?;
+                }
+                return self.visit_lvalue(lhs);
+            }
+            ExprKind::Unary(op, operand)
+                if matches!(
+                    op.kind,
+                    UnOpKind::PreInc | UnOpKind::PreDec | UnOpKind::PostInc | UnOpKind::PostDec
+                ) =>
+            {
+                self.visit_expr(operand)?;
+                return self.visit_lvalue(operand);
+            }
+            ExprKind::Delete(operand) => return self.visit_lvalue(operand),
+            ExprKind::Ident(_) | ExprKind::Member(..) => {
+                if let Some(id) = self.immutable_of(expr) {
+                    self.read(id, expr.span);
+                }
+            }
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
@@ -6,15 +6,51 @@ use crate::{
 use rayon::prelude::*;
 use solar_data_structures::{map::FxHashSet, parallel};
 
+mod abstract_contracts;
+mod checker;
+mod constants;
+mod control_flow;
+mod immutables;
+mod mutability;
+mod overrides;
+mod unused;
+pub(crate) mod using_for;
+
 pub(crate) fn check(gcx: Gcx<'_>) {
     parallel!(
         gcx.sess,
         gcx.hir.par_contract_ids().for_each(|id| {
             check_duplicate_definitions(gcx, &gcx.symbol_resolver.contract_scopes[id]);
+            overrides::check_contract(gcx, id);
+            abstract_contracts::check_contract(gcx, id);
+            using_for::check_contract(gcx, id);
         }),
         gcx.hir.par_source_ids().for_each(|id| {
             check_duplicate_definitions(gcx, &gcx.symbol_resolver.source_scopes[id]);
+            using_for::check_source(gcx, id);
+        }),
+    );
+    parallel!(
+        gcx.sess,
+        gcx.hir.par_function_ids().for_each(|id| {
+            checker::check_function(gcx, id);
+            control_flow::check_function(gcx, id);
+        }),
+        gcx.hir.par_variable_ids().for_each(|id| {
+            checker::check_variable(gcx, id);
+            constants::check_variable(gcx, id);
         }),
+        gcx.hir.par_contract_ids().for_each(|id| {
+            checker::check_contract(gcx, id);
+        }),
+    );
+    // These passes use the types and resolutions recorded by the checker above, so they cannot
+    // run in the same group as it.
+    parallel!(
+        gcx.sess,
+        unused::check(gcx),
+        gcx.hir.par_function_ids().for_each(|id| mutability::check_function(gcx, id)),
+        gcx.hir.par_contract_ids().for_each(|id| immutables::check_contract(gcx, id)),
     );
 }
 
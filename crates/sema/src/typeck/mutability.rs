@@ -0,0 +1,362 @@
+//! State mutability checks.
+//!
+//! Reports function bodies that do not respect the declared state mutability of the function, and
+//! suggests a stricter mutability when possible.
+//!
+//! Reference: <https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/analysis/ViewPureChecker.cpp>
+
+use crate::{
+    builtins::Builtin,
+    hir::{self, ExprKind, Res, StmtKind, UnOpKind, Visit},
+    ty::{Gcx, Ty, TyKind},
+};
+use solar_ast::{DataLocation, StateMutability};
+use solar_data_structures::Never;
+use solar_interface::Span;
+use std::ops::ControlFlow;
+
+/// Checks that the body of the given function respects its declared state mutability.
+pub(super) fn check_function(gcx: Gcx<'_>, id: hir::FunctionId) {
+    let f = gcx.hir.function(id);
+    if f.is_getter() || f.kind.is_modifier() {
+        return;
+    }
+    let Some(body) = f.body else { return };
+
+    let mut checker = MutabilityChecker::new(gcx, Some(f));
+    for modifier in f.modifiers {
+        let _ = checker.visit_modifier(modifier);
+        let hir::ItemId::Function(id) = modifier.id else { continue };
+        let (mutability, span) = infer_modifier_mutability(gcx, id);
+        if let Some(span) = span {
+            checker.report(mutability, modifier.span, Some(span));
+        }
+    }
+    for stmt in body {
+        let _ = checker.visit_stmt(stmt);
+    }
+
+    let best = checker.best;
+    if f.kind.is_function()
+        && !body.is_empty()
+        && !f.virtual_
+        && f.state_mutability != StateMutability::Payable
+        && !checker.has_errors
+        && rank(best) < rank(f.state_mutability)
+    {
+        let msg = format!("function state mutability can be restricted to `{best}`");
+        gcx.dcx().warn(msg).span(fn_span(f)).emit();
+    }
+}
+
+/// Returns the state mutability required by the body of the given modifier, and the span of the
+/// first expression that requires it.
+fn infer_modifier_mutability(gcx: Gcx<'_>, id: hir::FunctionId) -> (StateMutability, Option<Span>) {
+    let mut checker = MutabilityChecker::new(gcx, None);
+    for stmt in gcx.hir.function(id).body.unwrap_or_default() {
+        let _ = checker.visit_stmt(stmt);
+    }
+    (checker.best, checker.best_span)
+}
+
+struct MutabilityChecker<'gcx> {
+    gcx: Gcx<'gcx>,
+    /// The function being checked. `None` when inferring the mutability of a modifier.
+    function: Option<&'gcx hir::Function<'gcx>>,
+    /// The least strict mutability required by the body so far.
+    best: StateMutability,
+    best_span: Option<Span>,
+    /// Whether an expression with an unknown type was found, in which case the inferred
+    /// mutability may be too strict.
+    has_errors: bool,
+}
+
+impl<'gcx> MutabilityChecker<'gcx> {
+    fn new(gcx: Gcx<'gcx>, function: Option<&'gcx hir::Function<'gcx>>) -> Self {
+        Self { gcx, function, best: StateMutability::Pure, best_span: None, has_errors: false }
+    }
+
+    /// Records that `span` requires at least `mutability`, reporting an error if the current
+    /// function is declared with a stricter one.
+    ///
+    /// `modifier_span` is the span of the expression inside an invoked modifier that requires the
+    /// mutability, if any.
+    fn report(&mut self, mutability: StateMutability, span: Span, modifier_span: Option<Span>) {
+        if rank(mutability) > rank(self.best) {
+            self.best = mutability;
+            self.best_span = Some(modifier_span.unwrap_or(span));
+        }
+        let Some(f) = self.function else { return };
+        if rank(mutability) <= rank(f.state_mutability) {
+            return;
+        }
+
+        let declared = f.state_mutability;
+        let mut diag = match mutability {
+            StateMutability::View => {
+                let msg = format!(
+                    "function declared as `{declared}`, but this expression (potentially) reads \
+                     from the environment or state and thus requires `view`"
+                );
+                self.gcx.dcx().err(msg).span(span)
+            }
+            StateMutability::Payable if declared == StateMutability::Pure => {
+                let msg = format!(
+                    "function declared as `{declared}`, but this expression (potentially) reads \
+                     from the environment or state and thus requires `view`"
+                );
+                self.gcx.dcx().err(msg).span(span)
+            }
+            StateMutability::NonPayable => {
+                let msg = format!(
+                    "function declared as `{declared}`, but this expression (potentially) \
+                     modifies the state and thus requires non-payable (the default) or payable"
+                );
+                self.gcx.dcx().err(msg).span(span)
+            }
+            StateMutability::Payable => {
+                // Internal functions may be called from payable functions. Library functions
+                // cannot be payable.
+                let is_library =
+                    f.contract.is_some_and(|c| self.gcx.hir.contract(c).kind.is_library());
+                if !(f.kind.is_constructor() || f.visibility >= hir::Visibility::Public)
+                    || is_library
+                {
+                    return;
+                }
+                if modifier_span.is_some() {
+                    let msg = "this modifier uses `msg.value` and thus the function has to be \
+                               payable or internal";
+                    self.gcx.dcx().err(msg).span(span)
+                } else {
+                    let msg = "`msg.value` can only be used in payable public functions";
+                    self.gcx
+                        .dcx()
+                        .err(msg)
+                        .span(span)
+                        .help("make the function `payable` or use an internal function")
+                }
+            }
+            StateMutability::Pure => unreachable!(),
+        };
+        if let Some(modifier_span) = modifier_span {
+            diag = diag.span_note(modifier_span, "required by this expression in the modifier");
+        }
+        diag.emit();
+    }
+
+    /// Checks an expression that is assigned to, deleted, incremented, or decremented.
+    fn visit_lvalue(&mut self, expr: &'gcx hir::Expr<'gcx>) {
+        match expr.kind {
+            ExprKind::Tuple(exprs) => {
+                for expr in exprs.iter().flatten() {
+                    self.visit_lvalue(expr);
+                }
+            }
+            ExprKind::Ident(_) => self.check_ident(expr, true),
+            ExprKind::Member(base, _) => {
+                self.check_member(expr, base, true);
+                let _ = self.visit_expr(base);
+            }
+            ExprKind::Index(base, index) => {
+                self.check_index(expr, base, true);
+                let _ = self.visit_expr(base);
+                if let Some(index) = index {
+                    let _ = self.visit_expr(index);
+                }
+            }
+            _ => {
+                let _ = self.visit_expr(expr);
+            }
+        }
+    }
+
+    fn check_ident(&mut self, expr: &hir::Expr<'_>, writes: bool) {
+        let ExprKind::Ident(res) = expr.kind else { unreachable!() };
+        let res = match *res {
+            [res] => res,
+            _ => match self.gcx.res_of_expr(expr.id) {
+                Some(res) => res,
+                None => return,
+            },
+        };
+        self.check_res(res, expr.span, writes);
+    }
+
+    fn check_res(&mut self, res: Res, span: Span, writes: bool) {
+        match res {
+            Res::Item(hir::ItemId::Variable(id)) => {
+                let var = self.gcx.hir.variable(id);
+                if !var.is_state_variable() || var.is_constant() {
+                    return;
+                }
+                let mutability = if var.is_immutable() {
+                    // Immutables that are initialized with a number literal are pure.
+                    let is_literal = |ty: Ty<'_>| {
+                        matches!(ty.kind, TyKind::IntLiteral(..) | TyKind::RationalLiteral(..))
+                    };
+                    if var
+                        .initializer
+                        .and_then(|init| self.gcx.type_of_expr_opt(init.id))
+                        .is_some_and(is_literal)
+                    {
+                        return;
+                    }
+                    StateMutability::View
+                } else if writes {
+                    StateMutability::NonPayable
+                } else {
+                    StateMutability::View
+                };
+                self.report(mutability, span, None);
+            }
+            Res::Builtin(Builtin::This) => self.report(StateMutability::View, span, None),
+            _ => {}
+        }
+    }
+
+    fn check_member(&mut self, expr: &hir::Expr<'_>, base: &hir::Expr<'_>, writes: bool) {
+        let gcx = self.gcx;
+        let Some(member) = gcx.member_of_expr(expr.id) else {
+            self.has_errors = true;
+            return;
+        };
+        let mutability = match member.res {
+            Some(Res::Builtin(builtin)) => match builtin {
+                Builtin::MsgValue => StateMutability::Payable,
+                Builtin::MsgData | Builtin::MsgSig => return,
+                Builtin::BlockCoinbase
+                | Builtin::BlockTimestamp
+                | Builtin::BlockDifficulty
+                | Builtin::BlockPrevrandao
+                | Builtin::BlockNumber
+                | Builtin::BlockGaslimit
+                | Builtin::BlockChainid
+                | Builtin::BlockBasefee
+                | Builtin::BlockBlobbasefee
+                | Builtin::MsgSender
+                | Builtin::MsgGas
+                | Builtin::TxOrigin
+                | Builtin::TxGasPrice
+                | Builtin::AddressBalance
+                | Builtin::AddressCode
+                | Builtin::AddressCodehash => StateMutability::View,
+                Builtin::ArrayLength if self.is_storage(base) => StateMutability::View,
+                _ => return,
+            },
+            Some(res @ Res::Item(hir::ItemId::Variable(id))) => {
+                let var = gcx.hir.variable(id);
+                if var.is_state_variable() {
+                    return self.check_res(res, expr.span, writes);
+                }
+                // Struct field.
+                if !self.is_storage(base) {
+                    return;
+                }
+                if writes {
+                    StateMutability::NonPayable
+                } else {
+                    StateMutability::View
+                }
+            }
+            _ => return,
+        };
+        self.report(mutability, expr.span, None);
+    }
+
+    fn check_index(&mut self, expr: &hir::Expr<'_>, base: &hir::Expr<'_>, writes: bool) {
+        if self.is_storage(base) {
+            let mutability =
+                if writes { StateMutability::NonPayable } else { StateMutability::View };
+            self.report(mutability, expr.span, None);
+        }
+    }
+
+    fn check_call(&mut self, expr: &hir::Expr<'_>, callee: &hir::Expr<'_>) {
+        let Some(callee_ty) = self.expr_ty(callee) else { return };
+        if let TyKind::FnPtr(f) = callee_ty.kind {
+            // Calling a payable function only requires non-payable.
+            let mutability = match f.state_mutability {
+                StateMutability::Payable => StateMutability::NonPayable,
+                mutability => mutability,
+            };
+            self.report(mutability, expr.span, None);
+        }
+    }
+
+    /// Returns `true` if the value of the given expression is stored in storage.
+    fn is_storage(&mut self, expr: &hir::Expr<'_>) -> bool {
+        self.expr_ty(expr).is_some_and(|ty| {
+            ty.is_ref_at(DataLocation::Storage) || matches!(ty.kind, TyKind::Mapping(..))
+        })
+    }
+
+    fn expr_ty(&mut self, expr: &hir::Expr<'_>) -> Option<Ty<'gcx>> {
+        let ty = self.gcx.type_of_expr_opt(expr.id);
+        if ty.is_none_or(|ty| ty.has_error().is_err()) {
+            self.has_errors = true;
+            return None;
+        }
+        ty
+    }
+}
+
+impl<'gcx> Visit<'gcx> for MutabilityChecker<'gcx> {
+    type BreakValue = Never;
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            StmtKind::Emit(_) => self.report(StateMutability::NonPayable, stmt.span, None),
+            // Includes inline assembly, which is not lowered.
+            StmtKind::Err(_) => self.has_errors = true,
+            _ => {}
+        }
+        self.walk_stmt(stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            ExprKind::Assign(lhs, _, rhs) => {
+                self.visit_lvalue(lhs);
+                return self.visit_expr(rhs);
+            }
+            ExprKind::Delete(inner)
+            | ExprKind::Unary(
+                hir::UnOp {
+                    kind:
+                        UnOpKind::PreInc | UnOpKind::PreDec | UnOpKind::PostInc | UnOpKind::PostDec,
+                    ..
+                },
+                inner,
+            ) => {
+                self.visit_lvalue(inner);
+                return ControlFlow::Continue(());
+            }
+            ExprKind::Ident(_) => self.check_ident(expr, false),
+            ExprKind::Member(base, _) => self.check_member(expr, base, false),
+            ExprKind::Index(base, _) => self.check_index(expr, base, false),
+            ExprKind::Call(callee, ..) => self.check_call(expr, callee),
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
+
+/// Returns the strictness rank of the given state mutability, from `pure` (strictest) to
+/// `payable`.
+fn rank(mutability: StateMutability) -> u8 {
+    match mutability {
+        StateMutability::Pure => 0,
+        StateMutability::View => 1,
+        StateMutability::NonPayable => 2,
+        StateMutability::Payable => 3,
+    }
+}
+
+fn fn_span(f: &hir::Function<'_>) -> Span {
+    f.name.map_or(f.span, |name| name.span)
+}
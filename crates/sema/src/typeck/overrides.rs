@@ -0,0 +1,325 @@
+//! Override and virtual function checks.
+//!
+//! Reference: <https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/analysis/OverrideChecker.cpp>
+
+use crate::{
+    hir::{self, FunctionId},
+    ty::{Gcx, Ty},
+};
+use solar_ast::{StateMutability, Visibility};
+use solar_data_structures::{
+    map::{FxHashMap, FxHashSet, FxIndexMap},
+    smallvec::SmallVec,
+};
+use solar_interface::{
+    diagnostics::{DiagBuilder, ErrorGuaranteed},
+    Span, Symbol,
+};
+
+pub(super) fn check_contract(gcx: Gcx<'_>, id: hir::ContractId) {
+    let c = gcx.hir.contract(id);
+    let inherited = inherited_functions(gcx, id);
+    let overriders =
+        overridable_functions(gcx, id).map(|f| (signature(gcx, f), f)).collect::<Vec<_>>();
+
+    for &(sig, f_id) in &overriders {
+        check_virtual(gcx, c, f_id);
+        let bases = inherited
+            .iter()
+            .filter(|&&(base_sig, _)| base_sig == sig)
+            .map(|&(_, base)| base)
+            .collect::<SmallVec<[_; 4]>>();
+        check_override_list(gcx, f_id, &bases);
+        for &base in &bases {
+            check_override(gcx, f_id, base);
+        }
+    }
+
+    for var_id in c.variables() {
+        let var = gcx.hir.variable(var_id);
+        if var.override_ && var.getter.is_none() {
+            let msg = "`override` can only be used with public state variables";
+            gcx.dcx().err(msg).span(var_span(gcx, var_id)).emit();
+        }
+    }
+
+    check_ambiguous_overrides(gcx, c, &inherited, &overriders);
+}
+
+/// Returns the functions, modifiers and public state variable getters declared in the given
+/// contract, which can override or be overridden.
+fn overridable_functions(
+    gcx: Gcx<'_>,
+    id: hir::ContractId,
+) -> impl Iterator<Item = FunctionId> + use<'_> {
+    gcx.hir.contract(id).functions().filter(move |&f| !gcx.hir.function(f).kind.is_constructor())
+}
+
+/// Returns the functions inherited by the given contract that are not overridden in one of its
+/// bases, together with their signatures.
+///
+/// A function inherited through multiple paths is only returned once.
+fn inherited_functions<'gcx>(
+    gcx: Gcx<'gcx>,
+    id: hir::ContractId,
+) -> Vec<(Signature<'gcx>, FunctionId)> {
+    let c = gcx.hir.contract(id);
+    // Bases come after all the contracts deriving from them in the linearization, so iterating
+    // it in reverse computes the functions available in a base before they are needed.
+    let mut available = FxHashMap::<hir::ContractId, Vec<(Signature<'gcx>, FunctionId)>>::default();
+    for &base in c.linearized_bases.iter().rev() {
+        let mut functions = Vec::new();
+        let mut seen = FxHashSet::default();
+        for direct_base in gcx.hir.contract(base).bases {
+            // Bases are missing from the linearization only if it failed.
+            let Some(inherited) = available.get(direct_base) else { continue };
+            functions.extend(inherited.iter().filter(|&&(_, f)| seen.insert(f)));
+        }
+        if base == id {
+            return functions;
+        }
+        let own =
+            overridable_functions(gcx, base).map(|f| (signature(gcx, f), f)).collect::<Vec<_>>();
+        let own_sigs = FxHashSet::from_iter(own.iter().map(|&(sig, _)| sig));
+        functions.retain(|(sig, _)| !own_sigs.contains(sig));
+        functions.splice(0..0, own);
+        available.insert(base, functions);
+    }
+    Vec::new()
+}
+
+/// The signature by which functions override each other: their kind, name, and external
+/// parameter types.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) struct Signature<'gcx> {
+    kind: hir::FunctionKind,
+    name: Option<Symbol>,
+    parameters: &'gcx [Ty<'gcx>],
+}
+
+/// Returns the override signature of the given function.
+pub(super) fn signature(gcx: Gcx<'_>, id: FunctionId) -> Signature<'_> {
+    let f = gcx.hir.function(id);
+    let ty = gcx.type_of_item(id.into());
+    Signature {
+        kind: f.kind,
+        name: f.name.map(|name| name.name),
+        parameters: ty.as_externally_callable_function(gcx).parameters().unwrap(),
+    }
+}
+
+/// Checks the `virtual` specifier of a function.
+fn check_virtual(gcx: Gcx<'_>, c: &hir::Contract<'_>, f_id: FunctionId) {
+    let f = gcx.hir.function(f_id);
+    if !f.marked_virtual {
+        return;
+    }
+    let msg = if c.kind.is_library() {
+        format!("library {}s cannot be `virtual`", f.kind.to_str())
+    } else if f.visibility == Visibility::Private {
+        "`virtual` and `private` cannot be used together".to_string()
+    } else {
+        return;
+    };
+    gcx.dcx().err(msg).span(fn_span(gcx, f_id)).emit();
+}
+
+/// Checks that the `override` specifier of a function lists exactly the contracts that define
+/// the overridden functions.
+fn check_override_list(gcx: Gcx<'_>, f_id: FunctionId, bases: &[FunctionId]) {
+    let f = gcx.hir.function(f_id);
+    let span = fn_span(gcx, f_id);
+    let what = if f.kind.is_modifier() { "modifier" } else { "function" };
+
+    let mut expected = SmallVec::<[hir::ContractId; 4]>::new();
+    for &base in bases {
+        let c = gcx.hir.function(base).contract.unwrap();
+        if !expected.contains(&c) {
+            expected.push(c);
+        }
+    }
+
+    if bases.is_empty() {
+        if f.override_ {
+            let msg = format!("{what} has override specified but does not override anything");
+            gcx.dcx().err(msg).span(span).emit();
+        }
+        return;
+    }
+
+    if !f.override_ {
+        // Implementing a single interface function does not require `override`.
+        let implements_interface = expected.len() == 1
+            && gcx.hir.contract(expected[0]).kind.is_interface()
+            && !f.kind.is_modifier();
+        if !implements_interface {
+            let msg = format!("overriding {what} is missing `override` specifier");
+            let mut diag = gcx.dcx().err(msg).span(span);
+            for &base in bases {
+                diag = diag.span_note(fn_span(gcx, base), format!("overridden {what} is here"));
+            }
+            diag.emit();
+        }
+        if expected.len() == 1 {
+            return;
+        }
+    }
+
+    let specified = f.overrides;
+    for (i, &c) in specified.iter().enumerate() {
+        let name = gcx.hir.contract(c).name;
+        if specified[..i].contains(&c) {
+            let msg = format!("duplicate contract `{name}` found in override list");
+            gcx.dcx().err(msg).span(span).emit();
+        } else if !expected.contains(&c) {
+            let msg = format!("invalid contract specified in override list: `{name}`");
+            gcx.dcx().err(msg).span(span).emit();
+        }
+    }
+
+    if expected.len() > 1 || !specified.is_empty() {
+        let missing =
+            expected.iter().filter(|c| !specified.contains(c)).copied().collect::<Vec<_>>();
+        if !missing.is_empty() {
+            let plural = if missing.len() == 1 { "" } else { "s" };
+            let msg = format!(
+                "{what} needs to specify overridden contract{plural} {}",
+                contract_list(gcx, &missing)
+            );
+            gcx.dcx().err(msg).span(span).emit();
+        }
+    }
+}
+
+/// Checks that `f_id` can override `base_id`.
+fn check_override<'gcx>(gcx: Gcx<'gcx>, f_id: FunctionId, base_id: FunctionId) {
+    let f = gcx.hir.function(f_id);
+    let base = gcx.hir.function(base_id);
+    let span = fn_span(gcx, f_id);
+    let what = if f.kind.is_modifier() { "modifier" } else { "function" };
+    let base_note = |diag: DiagBuilder<'gcx, ErrorGuaranteed>| {
+        diag.span_note(fn_span(gcx, base_id), format!("overridden {what} is here"))
+    };
+
+    if base.is_getter() {
+        let msg = "cannot override public state variable";
+        base_note(gcx.dcx().err(msg).span(span)).emit();
+        return;
+    }
+
+    if !base.virtual_ {
+        let msg = format!("trying to override non-virtual {what}");
+        base_note(gcx.dcx().err(msg).span(span))
+            .help(format!("add `virtual` to the overridden {what}"))
+            .emit();
+    }
+
+    if f.kind.is_modifier() {
+        return;
+    }
+
+    if f.is_getter() {
+        if base.visibility != Visibility::External {
+            let msg = "public state variables can only override functions with external visibility";
+            base_note(gcx.dcx().err(msg).span(span)).emit();
+        }
+    } else if f.visibility != base.visibility
+        && !(base.visibility == Visibility::External && f.visibility == Visibility::Public)
+    {
+        let msg = "overriding function visibility differs";
+        base_note(gcx.dcx().err(msg).span(span)).emit();
+    }
+
+    if f.state_mutability != base.state_mutability {
+        let allowed = match base.state_mutability {
+            StateMutability::NonPayable => f.state_mutability != StateMutability::Payable,
+            StateMutability::View => f.state_mutability == StateMutability::Pure,
+            StateMutability::Pure | StateMutability::Payable => false,
+        };
+        if !allowed {
+            let msg = format!(
+                "overriding function changes state mutability from `{}` to `{}`",
+                base.state_mutability.to_str(),
+                f.state_mutability.to_str(),
+            );
+            base_note(gcx.dcx().err(msg).span(span)).emit();
+        }
+    }
+
+    let returns = |id: FunctionId| {
+        gcx.type_of_item(id.into()).as_externally_callable_function(gcx).returns().unwrap()
+    };
+    if returns(f_id) != returns(base_id) {
+        let msg = "overriding function return types differ";
+        base_note(gcx.dcx().err(msg).span(span)).emit();
+    }
+
+    if !f.is_getter() && f.body.is_none() && base.body.is_some() {
+        let msg =
+            "overriding an implemented function with an unimplemented function is not allowed";
+        base_note(gcx.dcx().err(msg).span(span)).emit();
+    }
+}
+
+/// Checks that functions that are inherited from multiple bases are overridden in the contract.
+fn check_ambiguous_overrides<'gcx>(
+    gcx: Gcx<'gcx>,
+    c: &hir::Contract<'_>,
+    inherited: &[(Signature<'gcx>, FunctionId)],
+    overriders: &[(Signature<'gcx>, FunctionId)],
+) {
+    let overridden = FxHashSet::from_iter(overriders.iter().map(|&(sig, _)| sig));
+    let mut by_signature = FxIndexMap::<Signature<'gcx>, SmallVec<[FunctionId; 4]>>::default();
+    for &(sig, f_id) in inherited {
+        if !overridden.contains(&sig) {
+            by_signature.entry(sig).or_default().push(f_id);
+        }
+    }
+
+    for bases in by_signature.values() {
+        if bases.len() <= 1 {
+            continue;
+        }
+
+        let f = gcx.hir.function(bases[0]);
+        let what = if f.kind.is_modifier() { "modifier" } else { "function" };
+        let name = f.name.map_or_else(|| f.kind.to_str().to_string(), |name| name.to_string());
+        let msg = format!("derived contract must override {what} `{name}`");
+        let mut diag = gcx.dcx().err(msg).span(c.name.span).note(format!(
+            "two or more base contracts define {what} with same name and parameter types"
+        ));
+        for &base in bases {
+            let base_c = gcx.hir.contract(gcx.hir.function(base).contract.unwrap());
+            diag = diag.span_note(fn_span(gcx, base), format!("definition in `{}`", base_c.name));
+        }
+        if bases.iter().any(|&base| gcx.hir.function(base).is_getter()) {
+            diag = diag.help(
+                "public state variables cannot be overridden; \
+                 change the inheritance layout or the names of the functions",
+            );
+        }
+        diag.emit();
+    }
+}
+
+/// Formats a list of contract names as "`A`, `B` and `C`".
+fn contract_list(gcx: Gcx<'_>, contracts: &[hir::ContractId]) -> String {
+    let names =
+        contracts.iter().map(|&c| format!("`{}`", gcx.hir.contract(c).name)).collect::<Vec<_>>();
+    match names.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+/// Returns the span of the function's name, or of the whole function if it is unnamed.
+pub(super) fn fn_span(gcx: Gcx<'_>, id: FunctionId) -> Span {
+    let f = gcx.hir.function(id);
+    f.name.map_or(f.span, |name| name.span)
+}
+
+fn var_span(gcx: Gcx<'_>, id: hir::VariableId) -> Span {
+    let var = gcx.hir.variable(id);
+    var.name.map_or(var.span, |name| name.span)
+}
@@ -0,0 +1,144 @@
+//! Unused variable lints.
+//!
+//! Reports unused local variables, function and try/catch parameters, named return variables, and
+//! unused private state variables.
+//!
+//! Like in solc, functions with empty bodies are not checked, and unnamed variables are never
+//! reported. Named return variables are also considered used if the function returns values
+//! explicitly, as they only serve as documentation in that case.
+//!
+//! Reference: <https://github.com/ethereum/solidity/blob/9d7cc42bc1c12bb43e9dccf8c6c36833fdfcbbca/libsolidity/analysis/StaticAnalyzer.cpp>
+
+use crate::{
+    hir::{self, ExprKind, Res, StmtKind, VarKind, Visit},
+    ty::Gcx,
+};
+use solar_data_structures::{map::FxHashSet, Never};
+use std::ops::ControlFlow;
+
+pub(super) fn check(gcx: Gcx<'_>) {
+    let mut collector = UseCollector {
+        gcx,
+        used: FxHashSet::default(),
+        has_assembly: false,
+        returns_values: false,
+    };
+    let mut assembly_functions = FxHashSet::default();
+    let mut returning_functions = FxHashSet::default();
+    for id in gcx.hir.function_ids() {
+        collector.has_assembly = false;
+        collector.returns_values = false;
+        let _ = collector.visit_nested_function(id);
+        // Parameter types may refer to constants in array lengths.
+        let f = gcx.hir.function(id);
+        for &var in f.parameters.iter().chain(f.returns) {
+            let _ = collector.visit_nested_var(var);
+        }
+        if collector.has_assembly {
+            assembly_functions.insert(id);
+        }
+        if collector.returns_values {
+            returning_functions.insert(id);
+        }
+    }
+    for contract in gcx.hir.contracts() {
+        for modifier in contract.base_args {
+            let _ = collector.visit_modifier(modifier);
+        }
+    }
+    for id in gcx.hir.variable_ids() {
+        if gcx.hir.variable(id).function.is_none() {
+            let _ = collector.visit_nested_var(id);
+        }
+    }
+    let used = collector.used;
+
+    for (id, var) in gcx.hir.variables_enumerated() {
+        if used.contains(&id) {
+            continue;
+        }
+        let Some(name) = var.name else { continue };
+        let (msg, help) = match var.kind {
+            VarKind::Statement => ("unused local variable", false),
+            VarKind::FunctionParam => ("unused function parameter", true),
+            VarKind::FunctionReturn => ("unused return variable", true),
+            VarKind::TryCatch => ("unused try/catch parameter", true),
+            VarKind::State if is_private_state_variable(var) => {
+                // Inline assembly may access the variable.
+                let contract = gcx.hir.contract(var.contract.unwrap());
+                if contract.all_functions().any(|f| assembly_functions.contains(&f)) {
+                    continue;
+                }
+                let msg = format!("unused state variable `{name}`");
+                gcx.dcx().warn(msg).span(var.span).emit();
+                continue;
+            }
+            _ => continue,
+        };
+        let Some(function) = var.function else { continue };
+        let f = gcx.hir.function(function);
+        // Inline assembly may access the variable.
+        if assembly_functions.contains(&function) {
+            continue;
+        }
+        if f.body.is_none_or(|body| body.is_empty())
+            || f.is_getter()
+            || (var.kind == VarKind::FunctionReturn && returning_functions.contains(&function))
+        {
+            continue;
+        }
+        let mut diag = gcx.dcx().warn(msg).span(var.span);
+        if help {
+            diag = diag.help("remove or comment out the variable name to silence this warning");
+        }
+        diag.emit();
+    }
+}
+
+/// Returns `true` if the given state variable is private, and can therefore only be accessed from
+/// its own contract.
+///
+/// Internal variables are not reported, as they can be used by derived contracts that are not part
+/// of this compilation.
+fn is_private_state_variable(var: &hir::Variable<'_>) -> bool {
+    var.contract.is_some() && var.visibility == Some(hir::Visibility::Private)
+}
+
+/// Collects all the variables that are referenced in function bodies and variable initializers.
+struct UseCollector<'gcx> {
+    gcx: Gcx<'gcx>,
+    used: FxHashSet<hir::VariableId>,
+    /// Whether the current function contains inline assembly.
+    has_assembly: bool,
+    /// Whether the current function contains a `return` statement with a value.
+    returns_values: bool,
+}
+
+impl<'gcx> Visit<'gcx> for UseCollector<'gcx> {
+    type BreakValue = Never;
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            StmtKind::Err(_) => self.has_assembly = true,
+            StmtKind::Return(Some(_)) => self.returns_values = true,
+            _ => {}
+        }
+        self.walk_stmt(stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        let res = match expr.kind {
+            ExprKind::Ident(_) => self.gcx.res_of_expr(expr.id),
+            ExprKind::Member(..) => self.gcx.member_of_expr(expr.id).and_then(|m| m.res),
+            _ => None,
+        };
+        if let Some(Res::Item(hir::ItemId::Variable(id))) = res {
+            self.used.insert(id);
+        }
+        self.walk_expr(expr)
+    }
+}
@@ -0,0 +1,311 @@
+//! `using for` directives.
+//!
+//! Validates the directives, and computes the functions they attach to types and the user-defined
+//! operators of user-defined value types.
+//!
+//! Reference: <https://docs.soliditylang.org/en/latest/contracts.html#using-for>
+
+use super::checker::is_comparison;
+use crate::{
+    builtins::{Member, MemberList},
+    hir::{self, BinOpKind, UnOpKind, UserDefinableOperator},
+    ty::{Gcx, Ty, TyFnPtr, TyKind},
+};
+use either::Either;
+use solar_ast::{StateMutability, Visibility};
+use solar_data_structures::BumpExt;
+
+/// Checks the `using for` directives declared at file level in the given source.
+pub(super) fn check_source(gcx: Gcx<'_>, id: hir::SourceId) {
+    for directive in gcx.hir.source(id).using_directives {
+        check_directive(gcx, id, directive);
+    }
+}
+
+/// Checks the `using for` directives declared in the given contract.
+pub(super) fn check_contract(gcx: Gcx<'_>, id: hir::ContractId) {
+    let contract = gcx.hir.contract(id);
+    for directive in contract.using_directives {
+        check_directive(gcx, contract.source, directive);
+    }
+}
+
+fn check_directive<'gcx>(
+    gcx: Gcx<'gcx>,
+    source: hir::SourceId,
+    directive: &hir::UsingDirective<'_>,
+) {
+    let ty = directive.ty.as_ref().map(|ty| (gcx.type_of_hir_ty(ty), ty.span));
+    if ty.is_some_and(|(ty, _)| ty.has_error().is_err()) {
+        return;
+    }
+
+    // Missing types are reported in AST validation.
+    if let (true, Some((ty, span))) = (directive.global, ty) {
+        let defined_in = match ty.kind {
+            TyKind::Struct(id) => Some((gcx.hir.strukt(id).source, gcx.hir.strukt(id).contract)),
+            TyKind::Enum(id) => Some((gcx.hir.enumm(id).source, gcx.hir.enumm(id).contract)),
+            TyKind::Udvt(_, id) => Some((gcx.hir.udvt(id).source, gcx.hir.udvt(id).contract)),
+            _ => None,
+        };
+        let msg = match defined_in {
+            None => Some("can only use `global` with user-defined types"),
+            Some((ty_source, ty_contract)) if ty_source != source || ty_contract.is_some() => Some(
+                "can only use `global` with types defined in the same source unit at file level",
+            ),
+            Some(_) => None,
+        };
+        if let Some(msg) = msg {
+            gcx.dcx().err(msg).span(span).emit();
+        }
+    }
+
+    let hir::UsingList::Functions(functions) = directive.list else { return };
+    let ty_display = |ty: Option<Ty<'gcx>>| match ty {
+        Some(ty) => ty.display(gcx).to_string(),
+        None => "*".to_string(),
+    };
+    for using in functions {
+        let f = gcx.hir.function(using.id);
+        let name = gcx.item_name(using.id);
+        if !f.is_free() && !f.contract.is_some_and(|c| gcx.hir.contract(c).kind.is_library()) {
+            let msg = "only file-level functions and library functions can be attached to a type \
+                       in a `using for` directive";
+            gcx.dcx().err(msg).span(using.span).emit();
+            continue;
+        }
+
+        if let Some(operator) = using.operator {
+            check_operator(gcx, directive, ty.map(|(ty, _)| ty), using, operator);
+            continue;
+        }
+
+        let Some(&first) = f.parameters.first() else {
+            let msg = format!(
+                "the function `{name}` does not have any parameters, \
+                 and therefore cannot be attached to the type `{}`",
+                ty_display(ty.map(|(ty, _)| ty)),
+            );
+            gcx.dcx().err(msg).span(using.span).emit();
+            continue;
+        };
+        let Some((ty, _)) = ty else { continue };
+        let param_ty = gcx.type_of_item(first.into());
+        let self_ty = match param_ty.kind {
+            TyKind::Ref(_, loc) if ty.is_reference_type() => ty.with_loc(gcx, loc),
+            _ => ty,
+        };
+        if param_ty.has_error().is_ok() && !self_ty.is_implicitly_convertible_to(gcx, param_ty) {
+            let msg = format!(
+                "the function `{name}` cannot be attached to the type `{}` because the type cannot \
+                 be implicitly converted to the first parameter of the function (`{}`)",
+                ty.display(gcx),
+                param_ty.display(gcx),
+            );
+            gcx.dcx().err(msg).span(using.span).emit();
+        }
+    }
+}
+
+fn check_operator<'gcx>(
+    gcx: Gcx<'gcx>,
+    directive: &hir::UsingDirective<'_>,
+    ty: Option<Ty<'gcx>>,
+    using: &hir::UsingFunction,
+    operator: UserDefinableOperator,
+) {
+    let dcx = gcx.dcx();
+    if !directive.global {
+        let msg = "user-defined operators can only be defined in a global `using for` directive";
+        dcx.err(msg).span(using.span).emit();
+        return;
+    }
+    let Some(ty) = ty else { return };
+    if !matches!(ty.kind, TyKind::Udvt(..)) {
+        let msg = "user-defined operators can only be implemented for user-defined value types";
+        dcx.err(msg).span(directive.ty.as_ref().unwrap().span).emit();
+        return;
+    }
+    let f = gcx.hir.function(using.id);
+    if !f.is_free() || f.state_mutability != StateMutability::Pure {
+        let msg = "only pure free functions can be used to define operators";
+        dcx.err(msg).span(using.span).emit();
+        return;
+    }
+
+    let name = gcx.item_name(using.id);
+    let (op, is_comparison) = match operator.to_op() {
+        Either::Left(op) => (op.to_str(), false),
+        Either::Right(op) => (op.to_str(), is_comparison(op)),
+    };
+    let parameters = gcx.item_parameter_types(using.id);
+    let (valid_arity, expected) = match operator {
+        UserDefinableOperator::Sub => (matches!(parameters.len(), 1 | 2), "one or two parameters"),
+        UserDefinableOperator::BitNot => (parameters.len() == 1, "exactly one parameter"),
+        _ => (parameters.len() == 2, "exactly two parameters"),
+    };
+    if !valid_arity || parameters.iter().any(|&param| param != ty) {
+        let msg = format!(
+            "wrong parameters in operator definition: the function `{name}` needs to have \
+             {expected} of type `{}` to be used for the operator `{op}`",
+            ty.display(gcx),
+        );
+        dcx.err(msg).span(using.span).emit();
+        return;
+    }
+
+    let expected = if is_comparison { gcx.types.bool } else { ty };
+    if *gcx.type_of_item(using.id.into()).returns().unwrap() != [expected] {
+        let msg = format!(
+            "wrong return parameters in operator definition: the function `{name}` needs to \
+             return exactly one value of type `{}` to be used for the operator `{op}`",
+            expected.display(gcx),
+        );
+        dcx.err(msg).span(using.span).emit();
+    }
+}
+
+/// Returns the functions attached by the `using for` directives that are visible in the given
+/// scope, along with the type they are attached to, or `None` for `*`.
+///
+/// These are the directives of the contract itself, but not of its bases, the file-level
+/// directives of the source, and the `global` directives of all sources.
+pub(crate) fn using_for_table<'gcx>(
+    gcx: Gcx<'gcx>,
+    source: hir::SourceId,
+    contract: Option<hir::ContractId>,
+) -> &'gcx [(Option<Ty<'gcx>>, hir::FunctionId)] {
+    let global_directives = gcx
+        .hir
+        .sources_enumerated()
+        .filter(|&(id, _)| id != source)
+        .flat_map(|(_, s)| s.using_directives.iter().filter(|d| d.global));
+    let directives = contract
+        .map(|id| gcx.hir.contract(id).using_directives)
+        .unwrap_or_default()
+        .iter()
+        .chain(gcx.hir.source(source).using_directives)
+        .chain(global_directives);
+
+    let mut table = Vec::new();
+    for directive in directives {
+        let ty = match &directive.ty {
+            Some(ty) => {
+                let ty = gcx.type_of_hir_ty(ty);
+                if ty.has_error().is_err() {
+                    continue;
+                }
+                Some(ty.peel_refs())
+            }
+            None => None,
+        };
+        match directive.list {
+            hir::UsingList::Library(id) => {
+                for id in gcx.hir.contract(id).functions() {
+                    let f = gcx.hir.function(id);
+                    if f.kind.is_function() && !f.is_getter() && f.visibility != Visibility::Private
+                    {
+                        table.push((ty, id));
+                    }
+                }
+            }
+            hir::UsingList::Functions(functions) => {
+                for using in functions.iter().filter(|using| using.operator.is_none()) {
+                    table.push((ty, using.id));
+                }
+            }
+        }
+    }
+    gcx.bump().alloc_vec(table)
+}
+
+/// Returns the functions attached to `ty` in the given scope, with their first parameter bound to
+/// the accessed expression.
+///
+/// See [`using_for_table`] for the directives that are visible in a scope.
+pub(crate) fn attached_functions<'gcx>(
+    gcx: Gcx<'gcx>,
+    source: hir::SourceId,
+    contract: Option<hir::ContractId>,
+    ty: Ty<'gcx>,
+) -> MemberList<'gcx> {
+    if let TyKind::Type(_)
+    | TyKind::Meta(_)
+    | TyKind::Super(_)
+    | TyKind::Module(_)
+    | TyKind::BuiltinModule(_)
+    | TyKind::Err(_) = ty.kind
+    {
+        return &[];
+    }
+
+    let mut members = Vec::<Member<'gcx>>::new();
+    for &(attached_ty, id) in gcx.using_for_table((source, contract)) {
+        if attached_ty.is_some_and(|attached_ty| attached_ty != ty.peel_refs()) {
+            continue;
+        }
+        let res = hir::Res::Item(id.into());
+        if members.iter().any(|m| m.res == Some(res)) {
+            continue;
+        }
+        let Some(name) = gcx.hir.function(id).name else { continue };
+        let TyKind::FnPtr(f) = gcx.type_of_item(id.into()).kind else { continue };
+        let Some((&first, parameters)) = f.parameters.split_first() else { continue };
+        if !ty.is_implicitly_convertible_to(gcx, first) {
+            continue;
+        }
+        let bound = gcx.mk_ty_fn_ptr(TyFnPtr {
+            parameters,
+            returns: f.returns,
+            state_mutability: f.state_mutability,
+            visibility: f.visibility,
+        });
+        members.push(Member::attached(name.name, bound, id));
+    }
+    gcx.bump().alloc_vec(members)
+}
+
+/// Returns the operators defined for the given user-defined value type by `global` `using for`
+/// directives, along with the functions that implement them.
+pub(crate) fn udvt_operators<'gcx>(
+    gcx: Gcx<'gcx>,
+    id: hir::UdvtId,
+) -> &'gcx [(UserDefinableOperator, hir::FunctionId)] {
+    let operators = gcx
+        .hir
+        .sources()
+        .flat_map(|source| source.using_directives)
+        .filter(|d| {
+            d.global
+                && d.ty.as_ref().is_some_and(|ty| {
+                    matches!(ty.kind, hir::TypeKind::Custom(hir::ItemId::Udvt(ty_id)) if ty_id == id)
+                })
+        })
+        .flat_map(|d| match d.list {
+            hir::UsingList::Functions(functions) => functions,
+            hir::UsingList::Library(_) => &[],
+        })
+        .filter_map(|using| Some((using.operator?, using.id)));
+    gcx.bump().alloc_from_iter(operators)
+}
+
+/// Returns the function that implements the given operator for the user-defined value type `ty`,
+/// if any.
+pub(super) fn user_defined_operator(
+    gcx: Gcx<'_>,
+    op: Either<UnOpKind, BinOpKind>,
+    ty: Ty<'_>,
+) -> Option<hir::FunctionId> {
+    let TyKind::Udvt(_, id) = ty.kind else { return None };
+    let arity = if op.is_left() { 1 } else { 2 };
+    gcx.udvt_operators(id)
+        .iter()
+        .find(|&&(operator, f)| {
+            let matches_op = match op {
+                Either::Left(UnOpKind::Neg) => operator == UserDefinableOperator::Sub,
+                op => operator.to_op() == op,
+            };
+            matches_op && gcx.hir.function(f).parameters.len() == arity
+        })
+        .map(|&(_, f)| f)
+}
@@ -0,0 +1,34 @@
+// The spans of the type errors below show how each expression is grouped.
+
+function associativity(uint a, uint b, int c, bool d) pure {
+    // (a - b) - c
+    a - b - c; //~ ERROR: cannot apply binary operator `-` to types `uint256` and `int256`
+    // (a / b) * c
+    a / b * c; //~ ERROR: cannot apply binary operator `*` to types `uint256` and `int256`
+    // a ** (b ** d)
+    a ** b ** d; //~ ERROR: cannot apply binary operator `**` to types `uint256` and `bool`
+}
+
+function exponentiation(uint a, uint b, bool d) pure {
+    // (-d) ** a
+    -d ** a; //~ ERROR: cannot apply unary operator `-` to type `bool`
+    // a * (b ** d)
+    a * b ** d; //~ ERROR: cannot apply binary operator `**` to types `uint256` and `bool`
+    // (a ** d) * b
+    a ** d * b; //~ ERROR: cannot apply binary operator `**` to types `uint256` and `bool`
+    // a + (b ** d)
+    a + b ** d; //~ ERROR: cannot apply binary operator `**` to types `uint256` and `bool`
+    // a < (b ** d)
+    a < b ** d; //~ ERROR: cannot apply binary operator `**` to types `uint256` and `bool`
+    // (a ** d) == b
+    a ** d == b; //~ ERROR: cannot apply binary operator `**` to types `uint256` and `bool`
+}
+
+// 256 - 1 - 1 == 254
+uint8 constant A = 256 - 1 - 1;
+// 2 * 2 ** 6 == 128
+uint8 constant B = 2 * 2 ** 6;
+// 2 ** 2 ** 3 == 256
+uint8 constant C = 2 ** 2 ** 3; //~ ERROR: is not implicitly convertible to expected type `uint8`
+// 10 - 2 ** 3 * 2 + 250 == 244
+uint8 constant D = 10 - 2 ** 3 * 2 + 250;
@@ -1,4 +1,4 @@
-function f() {
+function f() { //~ WARN: function state mutability can be restricted to `pure`
     uint i;
     do ++i; while (false);
     do i += 1; while (true && false);
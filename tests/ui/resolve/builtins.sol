@@ -1,4 +1,4 @@
-function f() {
+function f() { //~ WARN: function state mutability can be restricted to `view`
     uint x = block.timestamp;
     x;
 }
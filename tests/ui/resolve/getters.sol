@@ -8,7 +8,7 @@ contract C {
     mapping(string k1 => mapping(string k2 => bool v2)[] v1) public nestedArrayOfMaps;
     mapping(string k1 => mapping(string k2 => bool[] v2)[] v1) public nestedArrayOfMapsOfArrays;
 
-    function referenceNames() public {
+    function referenceNames() public { //~ WARN: function state mutability can be restricted to `view`
         simple;
         array;
         map;
@@ -19,7 +19,7 @@ contract C {
         nestedArrayOfMapsOfArrays;
     }
 
-    function referenceThis() public {
+    function referenceThis() public { //~ WARN: function state mutability can be restricted to `view`
         this.simple;
         this.array;
         this.map;
@@ -30,14 +30,14 @@ contract C {
         this.nestedArrayOfMapsOfArrays;
     }
 
-    function doCall() public {
-        bool x1 = this.simple();
-        bool x2 = this.array(0);
-        bool x3 = this.map("");
-        bool x4 = this.mapOfArrays("", 0);
-        bool x5 = this.nestedMap("", "");
-        bool x6 = this.nestedMapOfArrays("", "", 0);
-        bool x7 = this.nestedArrayOfMaps("", 0, "");
-        bool x8 = this.nestedArrayOfMapsOfArrays("", 0, "", 0);
+    function doCall() public { //~ WARN: function state mutability can be restricted to `view`
+        bool x1 = this.simple(); //~ WARN: unused local variable
+        bool x2 = this.array(0); //~ WARN: unused local variable
+        bool x3 = this.map(""); //~ WARN: unused local variable
+        bool x4 = this.mapOfArrays("", 0); //~ WARN: unused local variable
+        bool x5 = this.nestedMap("", ""); //~ WARN: unused local variable
+        bool x6 = this.nestedMapOfArrays("", "", 0); //~ WARN: unused local variable
+        bool x7 = this.nestedArrayOfMaps("", 0, ""); //~ WARN: unused local variable
+        bool x8 = this.nestedArrayOfMapsOfArrays("", 0, "", 0); //~ WARN: unused local variable
     }
 }
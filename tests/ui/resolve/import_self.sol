@@ -7,8 +7,8 @@ struct S {
 }
 
 contract C {
-    function f() external {
-        self1.self2.self2.self1.self2.S memory s;
-        S2 memory s2;
+    function f() external { //~ WARN: function state mutability can be restricted to `pure`
+        self1.self2.self2.self1.self2.S memory s; //~ WARN: unused local variable
+        S2 memory s2; //~ WARN: unused local variable
     }
 }
@@ -0,0 +1,14 @@
+contract Base {
+    function f() public virtual {}
+}
+
+contract Unrelated {
+    function f() public virtual {}
+}
+
+contract NotABase is Base {
+    function f() public override(Base, Unrelated) {} //~ ERROR: override is not a base contract
+}
+
+function free() override {} //~ ERROR: free functions cannot override
+function free2() override(Base) {} //~ ERROR: free functions cannot override
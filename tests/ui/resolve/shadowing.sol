@@ -0,0 +1,56 @@
+contract Base {
+    uint256 internal inherited;
+
+    function baseFn() internal {}
+}
+
+contract C is Base {
+    uint256 stateVar;
+
+    event E(uint256 stateVar);
+    error Err(uint256 stateVar);
+
+    struct S {
+        uint256 stateVar;
+    }
+
+    function f(uint256 stateVar) public pure { //~ WARN: this declaration shadows an existing declaration
+        stateVar;
+    }
+
+    function getState() public view returns (uint256) {
+        return stateVar;
+    }
+
+    function g() public returns (uint256 inherited) {} //~ WARN: this declaration shadows an existing declaration
+
+    function h() public pure {
+        uint256 baseFn; //~ WARN: this declaration shadows an existing declaration
+        uint256 block; //~ WARN: this declaration shadows a builtin symbol
+        uint256 msg; //~ WARN: this declaration shadows a builtin symbol
+        uint256 keccak256; //~ WARN: this declaration shadows a builtin symbol
+        uint256 this; //~ WARN: this declaration shadows a builtin symbol
+        baseFn; block; msg; keccak256; this;
+    }
+
+    function nested(uint256 x) public pure returns (uint256) {
+        {
+            uint256 x = 1; //~ WARN: this declaration shadows an existing declaration
+            x;
+        }
+        return x;
+    }
+
+    function siblings() public pure {
+        {
+            uint256 a;
+            a;
+        }
+        {
+            uint256 a;
+            a;
+        }
+    }
+
+    function fnTy(function(uint256) external fn) public {}
+}
@@ -1,6 +1,6 @@
 contract C {
     uint public x = 1;
-    function f() public returns(uint y) {
+    function f() public returns(uint y) { //~ WARN: function state mutability can be restricted to `view`
         y = this.x();
     }
 }
@@ -3,7 +3,7 @@ contract C {
     function g(bool x) public returns (uint a, uint b) {}
 
     function f(bool b) public {
-        try this.g(b) returns (uint a, uint b) {
+        try this.g(b) returns (uint a, uint b) { //~ WARN: this declaration shadows an existing declaration
             a = 1;
             b = 2;
         } catch Error(string memory s) {
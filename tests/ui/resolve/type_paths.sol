@@ -13,7 +13,7 @@ contract C {
         //~^ ERROR: unresolved symbol `Unknown`
     ) public {
         S memory e = S(0);
-        C.S memory f = C.S(1);
+        C.S memory f = C.S(1); //~ WARN: this declaration shadows an existing declaration
         self.C.S memory g = self.C.S(2);
         
         self.C.Unknown memory h = self.C.Unknown(3);
@@ -0,0 +1,12 @@
+contract C {}
+
+struct S {
+    uint256 x;
+}
+
+function f(uint256) pure {}
+
+using C for uint256; //~ ERROR: expected library, found contract
+using {S} for uint256; //~ ERROR: expected function, found struct
+using {missing} for uint256; //~ ERROR: unresolved symbol `missing`
+using {f} for Missing; //~ ERROR: unresolved symbol `Missing`
@@ -0,0 +1,84 @@
+struct S {
+    uint256 a;
+}
+
+struct WithMapping {
+    mapping(uint256 => uint256) m;
+}
+
+interface I {
+    function g(uint256 a, bool b) external;
+}
+
+contract C {
+    mapping(uint256 => uint256) m;
+    WithMapping withMapping;
+    S[] structs;
+
+    function f(uint256 a, bool b) public {}
+
+    function encode(bytes memory data, uint256[] memory arr, uint256[][] memory nested) public view {
+        function() internal internalFn = h;
+        function() external externalFn = this.h2;
+
+        abi.encode(1, true, "abc", data, arr, nested, S(1), externalFn);
+        abi.encode(m); //~ ERROR: cannot be ABI-encoded
+        abi.encode(withMapping); //~ ERROR: cannot be ABI-encoded
+        abi.encode(internalFn); //~ ERROR: cannot be ABI-encoded
+        abi.encode(C); //~ ERROR: cannot be ABI-encoded
+        abi.encode(1.5); //~ ERROR: cannot encode a literal
+
+        abi.encodePacked(uint8(1), "abc", data, arr);
+        abi.encodePacked(1); //~ ERROR: cannot perform packed encoding for a literal
+        abi.encodePacked(S(1)); //~ ERROR: not supported in packed mode
+        abi.encodePacked(nested); //~ ERROR: not supported in packed mode
+        abi.encodePacked(structs); //~ ERROR: not supported in packed mode
+
+        abi.encodeWithSelector(this.f.selector, 1, true);
+        abi.encodeWithSelector(); //~ ERROR: wrong argument count
+        abi.encodeWithSelector(1, 2); //~ ERROR: is not implicitly convertible
+        abi.encodeWithSignature("f(uint256,bool)", 1, true);
+        abi.encodeWithSignature(1); //~ ERROR: is not implicitly convertible
+        abi.encode({a: 1}); //~ ERROR: named arguments cannot be used
+    }
+
+    function encodeCall(I i) public view {
+        abi.encodeCall(this.f, (1, true));
+        abi.encodeCall(i.g, (1, true));
+        abi.encodeCall(I.g, (1, true));
+        abi.encodeCall(this.h2, ());
+        abi.encodeCall(this.single, 1);
+        abi.encodeCall(this.single, (1));
+        abi.encodeCall(f, (1, true)); //~ ERROR: expected an external function
+        abi.encodeCall(this.f, (1, 2)); //~ ERROR: is not implicitly convertible
+        abi.encodeCall(this.f, (1)); //~ ERROR: wrong argument count
+        abi.encodeCall(this.f); //~ ERROR: expected two arguments
+    }
+
+    function decode(bytes memory data) public pure {
+        (uint256 a, bool b) = abi.decode(data, (uint256, bool));
+        uint256[] memory arr = abi.decode(data, (uint256[]));
+        S memory s = abi.decode(data, (S));
+        abi.decode(data, ());
+        bool c = abi.decode(data, (uint256)); //~ ERROR: is not implicitly convertible
+        abi.decode(data, (1)); //~ ERROR: expected a type name
+        abi.decode(data, (uint256, )); //~ ERROR: tuple component cannot be empty
+        abi.decode(data, (WithMapping)); //~ ERROR: cannot be ABI-encoded
+        abi.decode(1, (uint256)); //~ ERROR: is not implicitly convertible
+        abi.decode(data); //~ ERROR: wrong argument count
+        a; b; arr; s; c;
+    }
+
+    function concat(string memory s, bytes memory b, bytes4 b4) public pure {
+        string memory s2 = string.concat(s, "abc", s);
+        bytes memory b2 = bytes.concat(b, b4, "abc", hex"01");
+        string.concat(s, b); //~ ERROR: is not implicitly convertible
+        bytes.concat(b, s); //~ ERROR: invalid type for argument in `bytes.concat`
+        bytes.concat(1); //~ ERROR: invalid type for argument in `bytes.concat`
+        s2; b2;
+    }
+
+    function h() internal {}
+    function h2() external {}
+    function single(uint256) external {}
+}
@@ -0,0 +1,53 @@
+interface I {
+    function f() external;
+    function g() external view returns (uint256);
+}
+
+abstract contract A is I {
+    function f() external {}
+    function h() internal virtual;
+    modifier m() virtual;
+}
+
+contract B is A {} //~ ERROR: contract `B` should be marked as abstract
+
+contract C is A {
+    function g() external pure returns (uint256) {
+        return 1;
+    }
+
+    function h() internal override {}
+    modifier m() override {
+        _;
+    }
+}
+
+abstract contract D is I {}
+
+contract E is I { //~ ERROR: contract `E` should be marked as abstract
+    function f() external {}
+}
+
+interface J {
+    uint256 constant X = 1; //~ ERROR: variables cannot be declared in interfaces
+
+    constructor() {} //~ ERROR: constructors cannot be defined in interfaces
+
+    function pub() public; //~ ERROR: functions in interfaces must be declared `external`
+    function intern() internal; //~ ERROR: functions in interfaces must be declared `external`
+    function implemented() external {} //~ ERROR: functions in interfaces cannot have an implementation
+
+    fallback() external;
+}
+
+library L {}
+
+contract Factory {
+    function create() external {
+        new I(); //~ ERROR: cannot instantiate an interface
+        new A(); //~ ERROR: cannot instantiate an abstract contract
+        new D(); //~ ERROR: cannot instantiate an abstract contract
+        new L(); //~ ERROR: cannot instantiate a library
+        new C();
+    }
+}
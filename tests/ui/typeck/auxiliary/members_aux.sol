@@ -0,0 +1,5 @@
+struct AuxStruct {
+    uint256 a;
+}
+
+function auxFn() pure returns (uint256) { return 0; }
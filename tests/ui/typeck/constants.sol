@@ -0,0 +1,38 @@
+uint8 constant A = 200;
+uint8 constant B = A + A; //~ ERROR: evaluation of constant value failed
+uint8 constant C = A * 2 / 2; //~ ERROR: evaluation of constant value failed
+uint8 constant D = A * 2; //~ ERROR: evaluation of constant value failed
+uint8 constant D2 = A / 2 + 27;
+uint8 constant X = 300; //~ ERROR: type `int_const` is not implicitly convertible to expected type `uint8`
+uint256 constant NEG = -1; //~ ERROR: type `int_const` is not implicitly convertible to expected type `uint256`
+int8 constant E = -128;
+int8 constant F = E - 1; //~ ERROR: evaluation of constant value failed
+int8 constant G = -E; //~ ERROR: evaluation of constant value failed
+int16 constant H = E / 2;
+uint256 constant ZERO = A - A;
+uint256 constant I = 1 / ZERO; //~ ERROR: evaluation of constant value failed
+uint256 constant J = 1 % ZERO; //~ ERROR: evaluation of constant value failed
+uint256 constant K = type(uint256).max;
+uint8 constant K2 = type(uint8).max + 1; //~ ERROR: evaluation of constant value failed
+int8 constant K3 = int8(type(int8).max) + 1; //~ ERROR: evaluation of constant value failed
+uint8 constant K4 = uint8(A * 2); //~ ERROR: evaluation of constant value failed
+uint8 constant K5 = uint8(uint256(A) * 2);
+bytes32 constant L = keccak256("a");
+bytes constant M = abi.encodePacked(uint256(1));
+
+function f() pure returns (uint256) {
+    return 1;
+}
+
+contract Con {
+    uint256 constant T = block.timestamp; //~ ERROR: initial value for constant variable has to be compile-time constant
+    uint256 constant N = f(); //~ ERROR: initial value for constant variable has to be compile-time constant
+    address constant THIS = address(this); //~ ERROR: initial value for constant variable has to be compile-time constant
+    uint256 constant GAS = gasleft(); //~ ERROR: initial value for constant variable has to be compile-time constant
+    uint256 constant LEN = new uint256[](1).length; //~ ERROR: initial value for constant variable has to be compile-time constant
+    address constant OWNER = address(0x1234);
+
+    function get() public pure returns (uint256, uint256, int256, bytes32, address) {
+        return (D2 + K + K5 + ZERO, T + N + GAS + LEN, H, L, OWNER == THIS ? OWNER : THIS);
+    }
+}
@@ -0,0 +1,65 @@
+library L {
+    uint256 internal constant MAX = 10;
+
+    struct S {
+        uint256 x;
+    }
+
+    function f(uint256 a) internal pure returns (uint256) {
+        return a + MAX;
+    }
+
+    function g() public pure returns (uint256) {
+        return 1;
+    }
+
+    function h() private pure {}
+}
+
+interface I {
+    enum E { A, B }
+
+    function ext(uint256) external returns (bool);
+}
+
+contract Base {
+    uint256 internal value;
+    uint256 private secret; //~ WARN: unused state variable `secret`
+    uint256 public constant LIMIT = 5;
+
+    function f() internal view returns (uint256) {
+        return value;
+    }
+
+    function e() external {}
+
+    function p() private {}
+}
+
+contract Derived is Base {
+    function test() public view {
+        uint256 a = L.f(1) + L.g() + L.MAX;
+        L.S memory s = L.S(a);
+        I.E e = I.E.A; //~ WARN: this declaration shadows an existing declaration
+        bytes4 sel = I.ext.selector;
+        uint256 b = Base.f() + Base.value + Base.LIMIT;
+        Derived.test;
+        s; e; sel; b;
+
+        L.h(); //~ ERROR: member `h` not found on type `type(library L)`
+        Base.secret; //~ ERROR: member `secret` not found on type `type(contract Base)`
+        Base.e; //~ ERROR: member `e` not found on type `type(contract Base)`
+        Base.p(); //~ ERROR: member `p` not found on type `type(contract Base)`
+    }
+}
+
+contract Other {
+    function test() public {
+        I.ext(1); //~ ERROR: cannot call function via contract type name
+        Base.f(); //~ ERROR: member `f` not found on type `type(contract Base)`
+        Base.e(); //~ ERROR: cannot call function via contract type name
+        Base.value; //~ ERROR: member `value` not found on type `type(contract Base)`
+        Base.LIMIT;
+        Base.e.selector;
+    }
+}
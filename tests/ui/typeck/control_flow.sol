@@ -0,0 +1,118 @@
+contract C {
+    error E();
+
+    uint256 x;
+
+    function missing() internal pure returns (uint256) {} //~ WARN: unnamed return variable can remain unassigned
+
+    // Named return variables are never reported: they are zero-initialized, and leaving them
+    // unassigned on some paths is a common way to return the default value.
+    function named() internal pure returns (uint256 r) {}
+
+    function namedSomePaths(bool c) internal pure returns (uint256 r) {
+        if (c) {
+            r = 1;
+        }
+    }
+
+    function someReturn(bool c) internal pure returns (uint256) { //~ WARN: unnamed return variable can remain unassigned
+        if (c) {
+            return 1;
+        }
+    }
+
+    function emptyReturn(bool c) internal pure returns (uint256) { //~ WARN: unnamed return variable can remain unassigned
+        if (c) {
+            return;
+        }
+        return 1;
+    }
+
+    function allPaths(bool c) internal pure returns (uint256) {
+        if (c) {
+            return 1;
+        } else {
+            return 2;
+        }
+    }
+
+    function reverts(bool c) internal pure returns (uint256) {
+        if (c) {
+            return 1;
+        }
+        revert E();
+    }
+
+    function revertCall(bool c) internal pure returns (uint256) {
+        if (c) {
+            return 1;
+        }
+        revert("no");
+    }
+
+    function infinite() internal pure returns (uint256) {
+        while (true) {}
+    }
+
+    function infiniteFor() internal pure returns (uint256) {
+        for (;;) {}
+    }
+
+    function loopBreak() internal pure returns (uint256) { //~ WARN: unnamed return variable can remain unassigned
+        while (true) {
+            break;
+        }
+    }
+
+    function loopReturn() internal pure returns (uint256) {
+        do {
+            return 1;
+        } while (true);
+    }
+
+    function unreachable() internal {
+        return;
+        x = 1; //~ WARN: unreachable code
+    }
+
+    function unreachableRevert() internal {
+        revert E();
+        x = 1; //~ WARN: unreachable code
+    }
+
+    function unreachableLoop() internal {
+        for (uint256 i = 0; i < 10; i++) { //~ WARN: unreachable code
+            break;
+            x = i; //~ WARN: unreachable code
+        }
+        while (true) {
+            continue;
+            x = 1; //~ WARN: unreachable code
+        }
+        x = 2; //~ WARN: unreachable code
+    }
+
+    function unreachableNext() internal { //~ WARN: function state mutability can be restricted to `pure`
+        for (uint256 i = 0; i < 10; i++) { //~ WARN: unreachable code
+            return;
+        }
+    }
+
+    function continueNext() internal {
+        for (uint256 i = 0; i < 10; i++) {
+            if (i == 1) {
+                continue;
+            }
+            x = i;
+        }
+    }
+
+    function unreachableBranches(bool c) internal {
+        if (c) {
+            return;
+        } else {
+            revert();
+        }
+        x = 1; //~ WARN: unreachable code
+    }
+}
@@ -0,0 +1,119 @@
+type U is uint64;
+
+contract Payable {
+    receive() external payable {}
+}
+
+contract NonPayable {}
+
+contract Derived is NonPayable {}
+
+contract C {
+    enum E { A, B }
+
+    function implicit(
+        uint8 u8,
+        uint16 u16,
+        int8 i8,
+        int16 i16,
+        bytes2 b2,
+        bytes4 b4,
+        address a,
+        address payable ap,
+        Derived d,
+        U u
+    ) public pure {
+        uint16 a1 = u8; //~ WARN: unused local variable
+        int16 a2 = u8; //~ ERROR: type `uint8` is not implicitly convertible to expected type `int16`
+        //~^ WARN: unused local variable
+        int16 a3 = i8; //~ WARN: unused local variable
+        bytes4 a4 = b2; //~ WARN: unused local variable
+        address a5 = ap; //~ WARN: unused local variable
+        NonPayable a6 = d; //~ WARN: unused local variable
+        bytes4 a7 = 0; //~ WARN: unused local variable
+        bytes4 a8 = 0x12345678; //~ WARN: unused local variable
+        bytes2 a9 = "ab"; //~ WARN: unused local variable
+        bytes memory a10 = "abc"; //~ WARN: unused local variable
+        string memory a11 = "abc"; //~ WARN: unused local variable
+
+        uint8 b1 = u16; //~ ERROR: type `uint16` is not implicitly convertible to expected type `uint8`
+        //~^ WARN: unused local variable
+        uint16 b2_ = i8; //~ ERROR: type `int8` is not implicitly convertible to expected type `uint16`
+        //~^ WARN: unused local variable
+        int8 b3 = u8; //~ ERROR: type `uint8` is not implicitly convertible to expected type `int8`
+        //~^ WARN: unused local variable
+        int8 b4_ = i16; //~ ERROR: type `int16` is not implicitly convertible to expected type `int8`
+        //~^ WARN: unused local variable
+        bytes2 b5 = b4; //~ ERROR: type `bytes4` is not implicitly convertible to expected type `bytes2`
+        //~^ WARN: unused local variable
+        address payable b6 = a; //~ ERROR: type `address` is not implicitly convertible to expected type `address payable`
+        //~^ WARN: unused local variable
+        Derived b7 = NonPayable(a); //~ ERROR: type `contract NonPayable` is not implicitly convertible to expected type `contract Derived`
+        //~^ WARN: unused local variable
+        address b8 = d; //~ ERROR: type `contract Derived` is not implicitly convertible to expected type `address`
+        //~^ WARN: unused local variable
+        bytes4 b9 = 1; //~ ERROR: type `int_const` is not implicitly convertible to expected type `bytes4`
+        //~^ WARN: unused local variable
+        bytes4 b10 = 0x1234; //~ ERROR: type `int_const` is not implicitly convertible to expected type `bytes4`
+        //~^ WARN: unused local variable
+        bytes2 b11 = "abc"; //~ ERROR: type `literal_string` is not implicitly convertible to expected type `bytes2`
+        //~^ WARN: unused local variable
+        U b12 = 1; //~ ERROR: type `int_const` is not implicitly convertible to expected type `U`
+        //~^ WARN: unused local variable
+        uint64 b13 = u; //~ ERROR: type `U` is not implicitly convertible to expected type `uint64`
+        //~^ WARN: unused local variable
+    }
+
+    function explicit(
+        uint16 u16,
+        uint160 u160,
+        int8 i8,
+        bytes2 b2,
+        bytes20 b20,
+        address a,
+        address payable ap,
+        bytes memory b,
+        E e,
+        U u
+    ) public pure {
+        uint8(u16);
+        int16(u16);
+        uint8(i8);
+        bytes1(b2);
+        bytes4(b2);
+        uint16(b2);
+        bytes2(u16);
+        address(u160);
+        address(b20);
+        address(ap);
+        payable(a);
+        uint160(a);
+        bytes20(a);
+        NonPayable(a);
+        Payable(ap);
+        address(NonPayable(a));
+        payable(Payable(ap));
+        bytes4(b);
+        string(b);
+        uint256(e);
+        E(u16);
+        E(1);
+        address(0);
+        payable(0);
+
+        int8(u16); //~ ERROR: explicit type conversion not allowed from `uint16` to `int8`
+        uint32(b2); //~ ERROR: explicit type conversion not allowed from `bytes2` to `uint32`
+        int16(b2); //~ ERROR: explicit type conversion not allowed from `bytes2` to `int16`
+        address(u16); //~ ERROR: explicit type conversion not allowed from `uint16` to `address`
+        address(i8); //~ ERROR: explicit type conversion not allowed from `int8` to `address`
+        uint160(ap); //~ ERROR: explicit type conversion not allowed from `address payable` to `uint160`
+        Payable(a); //~ ERROR: explicit type conversion not allowed from `address` to `contract Payable`
+        payable(NonPayable(a)); //~ ERROR: explicit type conversion not allowed from `contract NonPayable` to `address payable`
+        payable(u160); //~ ERROR: explicit type conversion not allowed from `uint160` to `address payable`
+        bool(u16); //~ ERROR: explicit type conversion not allowed from `uint16` to `bool`
+        E(-1); //~ ERROR: explicit type conversion not allowed from `int_const` to `enum C.E`
+        bytes4(1); //~ ERROR: explicit type conversion not allowed from `int_const` to `bytes4`
+        uint64(u); //~ ERROR: explicit type conversion not allowed from `U` to `uint64`
+        U(u16); //~ ERROR: explicit type conversion not allowed from `uint16` to `U`
+    }
+}
@@ -0,0 +1,70 @@
+contract C {
+    struct S {
+        uint256 a;
+        uint256[] b;
+    }
+
+    struct M {
+        mapping(uint256 => uint256) m;
+    }
+
+    S s;
+    S[] ss;
+    uint256[] arr;
+    uint256[2] fixedArr;
+    M m1;
+    M m2;
+    mapping(uint256 => uint256) map;
+
+    function copies(S memory sm, uint256[] calldata ac) internal {
+        s = sm;
+        ss[0] = sm;
+        arr = ac;
+        (s, arr) = (sm, ac);
+        S memory copy = s;
+        copy.a = 1;
+        uint256[] memory copy2 = ac;
+        copy2[0] = 1;
+    }
+
+    function pointers(S memory sm, uint256[] calldata ac) internal {
+        S storage p = s;
+        p = ss[0];
+        p = sm; //~ ERROR: is not implicitly convertible to expected type
+        uint256[] storage q = ac; //~ ERROR: is not implicitly convertible to expected type
+        S storage r = S(1, new uint256[](0)); //~ ERROR: is not implicitly convertible to expected type
+        uint256[] calldata c = arr; //~ ERROR: is not implicitly convertible to expected type
+        takesStorage(sm); //~ ERROR: is not implicitly convertible to expected type
+        takesStorage(p);
+        p;
+        q;
+        r;
+        c;
+    }
+
+    function mismatch(uint256[] memory am) internal {
+        fixedArr = am; //~ ERROR: is not implicitly convertible to expected type
+    }
+
+    function readOnly(S calldata sc, uint256[] calldata ac, uint256[][] calldata acc) external { //~ WARN: function state mutability can be restricted to `pure`
+        sc.a = 1; //~ ERROR: calldata structs are read-only
+        ac[0] = 1; //~ ERROR: calldata arrays are read-only
+        acc[0][1] = 1; //~ ERROR: calldata arrays are read-only
+        ac[0]++; //~ ERROR: calldata arrays are read-only
+        delete ac[0]; //~ ERROR: calldata arrays are read-only
+        (ac[1], sc.a) = (1, 2);
+        //~^ ERROR: calldata arrays are read-only
+        //~| ERROR: calldata structs are read-only
+        ac = ac;
+    }
+
+    function mappings(M storage mp) internal {
+        m1 = m2; //~ ERROR: types in storage containing (nested) mappings cannot be assigned to
+        map = map; //~ ERROR: types in storage containing (nested) mappings cannot be assigned to
+        mp = m1; //~ ERROR: types in storage containing (nested) mappings cannot be assigned to
+        M storage local = m1;
+        local = m2;
+    }
+
+    function takesStorage(S storage) internal {}
+}
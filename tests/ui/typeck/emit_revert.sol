@@ -0,0 +1,43 @@
+event Transfer(address indexed from, address indexed to, uint256 amount);
+error Unauthorized(address caller);
+error Empty();
+
+library L {
+    event LibEvent(uint256 x);
+    error LibError(uint256 x);
+}
+
+contract C {
+    event Log(string message);
+
+    function f() public {}
+
+    function emits() public {
+        emit Transfer(msg.sender, address(0), 1);
+        emit Transfer({from: msg.sender, to: address(0), amount: 1});
+        emit Log("abc");
+        emit L.LibEvent(1);
+        emit Transfer(msg.sender, address(0)); //~ ERROR: wrong argument count for event call
+        emit Transfer(msg.sender, address(0), "abc"); //~ ERROR: is not implicitly convertible
+        emit Transfer({from: msg.sender, to: address(0), value: 1}); //~ ERROR: named argument `value` does not match event declaration
+        emit Empty(); //~ ERROR: expression has to be an event invocation
+        emit f(); //~ ERROR: expression has to be an event invocation
+        Log("abc"); //~ ERROR: event invocations have to be prefixed by `emit`
+    }
+
+    function reverts(uint256 x) public view {
+        if (x == 0) revert Unauthorized(msg.sender);
+        if (x == 1) revert Unauthorized({caller: msg.sender});
+        if (x == 2) revert L.LibError(1);
+        if (x == 3) revert Empty(1); //~ ERROR: wrong argument count for error call
+        if (x == 4) revert Unauthorized(1); //~ ERROR: is not implicitly convertible
+        if (x == 5) revert Log("abc"); //~ ERROR: expression has to be an error
+        if (x == 6) revert("abc");
+        require(x > 7, Unauthorized(msg.sender));
+        require(x > 8, L.LibError(x));
+        require(x > 9, Unauthorized(x)); //~ ERROR: is not implicitly convertible
+        require(x, Empty()); //~ ERROR: is not implicitly convertible
+        Empty(); //~ ERROR: errors can only be used with `revert` statements
+        require(x > 10, "abc");
+    }
+}
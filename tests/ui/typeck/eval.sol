@@ -35,4 +35,7 @@ contract C {
     function k(uint[--x] memory) public {} //~ ERROR: evaluation of constant value failed
     function l(uint[stateVar] memory) public {} //~ ERROR: evaluation of constant value failed
     function m(uint[stateVarPublic] memory) public {} //~ ERROR: evaluation of constant value failed
+    function n(uint[7 / 2] memory) public {} //~ ERROR: evaluation of constant value failed
+    function o(uint[uint8(1.5)] memory) public {} //~ ERROR: evaluation of constant value failed
+    function p(uint[1.5 << 1] memory) public {} //~ ERROR: evaluation of constant value failed
 }
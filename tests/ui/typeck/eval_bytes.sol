@@ -0,0 +1,37 @@
+bytes32 constant ROLE = keccak256("MINTER_ROLE");
+bytes4 constant SEL = bytes4(keccak256("f()"));
+bytes constant ABC = "abc";
+bytes2 constant BC = "bc";
+bytes2 constant TOO_LONG = bytes2(ABC); //~ ERROR: evaluation of constant value failed
+
+contract C {
+    uint256[uint8(bytes1(ROLE))] role;
+    uint256[uint8(bytes1(SEL))] selector;
+    uint256[uint8(bytes1(keccak256("")))] emptyKeccak;
+    uint256[uint16(bytes2(sha256("")))] emptySha;
+    uint256[uint8(bytes1(keccak256(ABC)))] bytesConstant;
+    uint256[uint8(bytes1(keccak256(abi.encodePacked("a", "bc"))))] packedStrings;
+    uint256[uint8(bytes1(keccak256(abi.encodePacked(uint8(0x61), BC))))] packedTyped;
+    uint256[uint8(bytes1(keccak256(bytes.concat("a", BC))))] concat;
+    uint256[uint16(bytes2("ab"))] stringToBytes;
+    uint256[uint8(bytes1(hex"2a"))] hexLiteral;
+    uint256[uint24(bytes3(abi.encodePacked(uint16(1), true)))] packedBool;
+    uint256[uint16(bytes2(abi.encodePacked(int8(-1), uint8(2))))] packedNegative;
+    uint256[uint160(bytes20(abi.encodePacked(address(2))))] packedAddress;
+
+    function f() public view {
+        uint256[1] storage x = role; //~ ERROR: type `uint256[159] storage` is not implicitly convertible
+        x = selector; //~ ERROR: type `uint256[38] storage` is not implicitly convertible
+        x = emptyKeccak; //~ ERROR: type `uint256[197] storage` is not implicitly convertible
+        x = emptySha; //~ ERROR: type `uint256[58288] storage` is not implicitly convertible
+        x = bytesConstant; //~ ERROR: type `uint256[78] storage` is not implicitly convertible
+        x = packedStrings; //~ ERROR: type `uint256[78] storage` is not implicitly convertible
+        x = packedTyped; //~ ERROR: type `uint256[78] storage` is not implicitly convertible
+        x = concat; //~ ERROR: type `uint256[78] storage` is not implicitly convertible
+        x = stringToBytes; //~ ERROR: type `uint256[24930] storage` is not implicitly convertible
+        x = hexLiteral; //~ ERROR: type `uint256[42] storage` is not implicitly convertible
+        x = packedBool; //~ ERROR: type `uint256[257] storage` is not implicitly convertible
+        x = packedNegative; //~ ERROR: type `uint256[65282] storage` is not implicitly convertible
+        x = packedAddress; //~ ERROR: type `uint256[2] storage` is not implicitly convertible
+    }
+}
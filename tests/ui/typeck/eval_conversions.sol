@@ -0,0 +1,45 @@
+enum E {
+    A,
+    B,
+    C
+}
+
+uint8 constant SMALL = 200;
+int8 constant NEG = -1;
+bytes4 constant SEL = 0x12345678;
+
+library L {
+    uint256 constant SIZE = 3;
+}
+
+contract C {
+    uint256[type(uint8).max + 1] maxPlusOne;
+    uint256[uint256(int256(type(int8).min) + 256)] minPlus256;
+    uint256[type(uint16).max] maxU16;
+    uint256[uint8(uint16(300))] truncated;
+    uint256[uint8(int8(-1))] signExtended;
+    uint256[uint16(int16(NEG))] negU16;
+    uint256[uint256(uint8(NEG))] negU8;
+    uint256[uint32(SEL)] fromBytes;
+    uint256[uint16(bytes2(SEL))] bytesTruncated;
+    uint256[uint64(bytes8(SEL)) >> 32] bytesPadded;
+    uint256[uint8(E.C) + 1] enumValue;
+    uint256[uint8(type(E).max)] enumMax;
+    uint256[L.SIZE] qualified;
+
+    function f() public view {
+        uint256[1] storage x = maxPlusOne; //~ ERROR: type `uint256[256] storage` is not implicitly convertible
+        x = minPlus256; //~ ERROR: type `uint256[128] storage` is not implicitly convertible
+        x = maxU16; //~ ERROR: type `uint256[65535] storage` is not implicitly convertible
+        x = truncated; //~ ERROR: type `uint256[44] storage` is not implicitly convertible
+        x = signExtended; //~ ERROR: type `uint256[255] storage` is not implicitly convertible
+        x = negU16; //~ ERROR: type `uint256[65535] storage` is not implicitly convertible
+        x = negU8; //~ ERROR: type `uint256[255] storage` is not implicitly convertible
+        x = fromBytes; //~ ERROR: type `uint256[305419896] storage` is not implicitly convertible
+        x = bytesTruncated; //~ ERROR: type `uint256[4660] storage` is not implicitly convertible
+        x = bytesPadded; //~ ERROR: type `uint256[305419896] storage` is not implicitly convertible
+        x = enumValue; //~ ERROR: type `uint256[3] storage` is not implicitly convertible
+        x = enumMax; //~ ERROR: type `uint256[2] storage` is not implicitly convertible
+        x = qualified; //~ ERROR: type `uint256[3] storage` is not implicitly convertible
+    }
+}
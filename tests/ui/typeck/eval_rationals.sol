@@ -0,0 +1,26 @@
+uint256 constant DENOM = 1e18 / 3 * 3;
+uint256 constant HALF_ETHER = 0.5 ether;
+
+contract C {
+    uint256[1.5 ether / 1e17] ether_;
+    uint256[2 days / 1 hours] time;
+    uint256[(7 / 2) * 2] exactDivision;
+    uint256[7.5 % 2 * 2] truncatedRemainder;
+    uint256[-7.5 % 2 * -2] negativeRemainder;
+    uint256[2 ** -2 * 8] negativeExponent;
+    uint256[0.5 < 1 ? 4 : 2] comparison;
+    uint256[DENOM / 1e17] constantDivision;
+    uint256[HALF_ETHER / 1 gwei] subdenomination;
+
+    function f() public view {
+        uint256[1] storage x = ether_; //~ ERROR: type `uint256[15] storage` is not implicitly convertible
+        x = time; //~ ERROR: type `uint256[48] storage` is not implicitly convertible
+        x = exactDivision; //~ ERROR: type `uint256[7] storage` is not implicitly convertible
+        x = truncatedRemainder; //~ ERROR: type `uint256[3] storage` is not implicitly convertible
+        x = negativeRemainder; //~ ERROR: type `uint256[3] storage` is not implicitly convertible
+        x = negativeExponent; //~ ERROR: type `uint256[2] storage` is not implicitly convertible
+        x = comparison; //~ ERROR: type `uint256[4] storage` is not implicitly convertible
+        x = constantDivision; //~ ERROR: type `uint256[10] storage` is not implicitly convertible
+        x = subdenomination; //~ ERROR: type `uint256[500000000] storage` is not implicitly convertible
+    }
+}
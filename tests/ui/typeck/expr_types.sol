@@ -0,0 +1,58 @@
+contract C {
+    uint256 s = "hello"; //~ ERROR: is not implicitly convertible to expected type
+
+    function f(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+
+    function literals() public pure {
+        uint x = "hello"; //~ ERROR: type `literal_string` is not implicitly convertible to expected type `uint256`
+        uint8 a = 255;
+        uint8 b = 256; //~ ERROR: is not implicitly convertible to expected type `uint8`
+        uint8 c = 256 - 1 - 1;
+        uint8 d = 2 * 2 ** 6;
+        int8 e = -128;
+        int8 g = 128; //~ ERROR: is not implicitly convertible to expected type `int8`
+        uint256 h = -1; //~ ERROR: is not implicitly convertible to expected type `uint256`
+        bool i = 1; //~ ERROR: is not implicitly convertible to expected type `bool`
+        uint256 j = 1 / 0; //~ ERROR: division by zero
+        x; a; b; c; d; e; g; h; i; j;
+    }
+
+    function operators(uint8 a, int16 b, bool c) public pure {
+        uint16 x = a + a;
+        int16 y = b + b;
+        int16 u = a + b; //~ ERROR: cannot apply binary operator `+` to types `uint8` and `int16`
+        bool z = a + c; //~ ERROR: cannot apply binary operator `+` to types `uint8` and `bool`
+        bool w = !a; //~ ERROR: cannot apply unary operator `!` to type `uint8`
+        uint8 v = a + x; //~ ERROR: is not implicitly convertible to expected type `uint8`
+        x; y; z; w; v; u;
+    }
+
+    function calls() public pure {
+        f(1, 2);
+        f(1); //~ ERROR: wrong argument count for function call: 1 arguments given but expected 2
+        f({a: 1, b: 2});
+        f({a: 1, c: 2}); //~ ERROR: named argument `c` does not match function declaration
+        f({a: 1, a: 2}); //~ ERROR: duplicate named argument `a`
+        f(true, 2); //~ ERROR: is not implicitly convertible to expected type `uint256`
+    }
+
+    function returns1() public pure returns (uint256) {
+        return true; //~ ERROR: is not implicitly convertible to expected type `uint256`
+    }
+
+    function returns2() public pure returns (uint256, bool) {
+        return 1; //~ ERROR: different number of arguments in return statement than in returns declaration: expected 2, found 1
+    }
+
+    function returns3() public pure {
+        return 1; //~ ERROR: function does not declare any return values
+    }
+
+    function tuples() public pure {
+        (uint256 a, bool b) = (1, true);
+        (uint256 c, bool d) = (1, 2, 3); //~ ERROR: different number of components on the left hand side (2) than on the right hand side (3)
+        a; b; c; d;
+    }
+}
@@ -0,0 +1,44 @@
+contract D {
+    constructor() payable {}
+}
+
+contract E {}
+
+contract C {
+    function ext() external {}
+    function extPayable() external payable {}
+    function pub() public {}
+    function int_() internal {}
+
+    function test(address a, function() external fp, function() internal ip) public {
+        bytes4 s1 = this.ext.selector;
+        bytes4 s2 = this.pub.selector;
+        bytes4 s3 = fp.selector;
+        address a1 = this.ext.address;
+        address a2 = fp.address;
+        s1; s2; s3; a1; a2;
+
+        this.extPayable{value: 1, gas: 1000}();
+        this.ext{gas: 1000}();
+        a.call{value: 1, gas: 1}("");
+        a.staticcall{gas: 1}("");
+        new D{value: 1, salt: bytes32(0)}();
+        new E{salt: 0}();
+
+        ip.selector; //~ ERROR: member `selector` not found on type `function ()`
+        int_.selector; //~ ERROR: member `selector` not found on type `function ()`
+        this.extPayable.value(1)(); //~ ERROR: using `.value(...)` is deprecated
+        this.ext.gas(1)(); //~ ERROR: using `.gas(...)` is deprecated
+
+        this.ext{value: 1}(); //~ ERROR: cannot set option `value` on a non-payable function type
+        this.ext{gas: 1, gas: 2}(); //~ ERROR: duplicate option `gas`
+        this.ext{salt: 0}(); //~ ERROR: function call option `salt` can only be used with `new`
+        this.ext{foo: 0}(); //~ ERROR: unknown call option `foo`; valid options are `salt`, `value` and `gas`
+        this.ext{gas: true}(); //~ ERROR: type `bool` is not implicitly convertible to expected type `uint256`
+        new D{gas: 1}(); //~ ERROR: function call option `gas` cannot be used with `new`
+        new E{value: 1}(); //~ ERROR: cannot set option `value` on a non-payable function type
+        a.delegatecall{value: 1}(""); //~ ERROR: cannot set option `value` on a non-payable function type
+        int_{gas: 1}(); //~ ERROR: function call options can only be set on external function calls or contract creations
+        ip{value: 1}(); //~ ERROR: function call options can only be set on external function calls or contract creations
+    }
+}
@@ -0,0 +1,86 @@
+contract Base {
+    uint256 immutable base = 1;
+}
+
+contract C is Base {
+    uint256 immutable init = 1;
+    uint256 immutable a;
+    uint256 immutable b;
+    uint256 immutable c;
+    uint256 immutable d;
+    uint256 immutable never; //~ ERROR: immutable variable `never` is never initialized
+    uint256 immutable e;
+    uint256 immutable f;
+    uint256 x = a + base; //~ ERROR: immutable variable `a` is read before it is initialized
+    uint256 immutable g = init;
+
+    constructor(uint256 n) {
+        init = 2; //~ ERROR: immutable variable `init` is already initialized
+        a = 1;
+        a = 2; //~ ERROR: immutable variable `a` is already initialized
+        uint256 y = b + base; //~ ERROR: immutable variable `b` is read before it is initialized
+        b = y;
+        for (uint256 i = 0; i < n; i++) {
+            c = i; //~ ERROR: immutable variables cannot be initialized inside a loop
+        }
+        while (n > 0) {
+            d = n--; //~ ERROR: immutable variables cannot be initialized inside a loop
+        }
+        (e, f) = (1, 2);
+        x = e + f;
+    }
+
+    function get() public view returns (uint256) {
+        return never + g;
+    }
+}
+
+abstract contract Abstract {
+    uint256 immutable value; //~ ERROR: immutable variable `value` is never initialized
+}
+
+contract Ok {
+    address immutable owner;
+    uint256 immutable created;
+
+    constructor() {
+        owner = msg.sender;
+        if (owner != address(0)) {
+            created = block.timestamp;
+        } else {
+            created = 0;
+        }
+    }
+}
+
+contract Conditional {
+    uint256 immutable onlyIf; //~ ERROR: immutable variable `onlyIf` is not initialized on all paths
+    uint256 immutable readAfterIf; //~ ERROR: immutable variable `readAfterIf` is not initialized on all paths
+    uint256 immutable twice;
+    uint256 immutable inTry; //~ ERROR: immutable variable `inTry` is not initialized on all paths
+    uint256 immutable allClauses;
+
+    constructor(bool c) {
+        if (c) onlyIf = 1;
+
+        if (c) readAfterIf = 1;
+        uint256 y = readAfterIf; //~ ERROR: immutable variable `readAfterIf` is read before it is initialized
+        y;
+
+        if (c) twice = 1;
+        twice = 2; //~ ERROR: immutable variable `twice` is already initialized
+
+        try this.ext() {
+            inTry = 1;
+        } catch {}
+
+        try this.ext() {
+            allClauses = 1;
+        } catch {
+            allClauses = 2;
+        }
+        allClauses;
+    }
+
+    function ext() external {}
+}
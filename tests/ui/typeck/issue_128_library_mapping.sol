@@ -10,6 +10,7 @@ struct StructTest {
 
 library TestLibrary {
     function testFunction(StructTest memory testParameter) external view returns (uint256) {
+    //~^ ERROR: only valid in storage because it contains a (nested) mapping
         return testParameter.a;
     }
 }
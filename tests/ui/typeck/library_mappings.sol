@@ -1,6 +1,5 @@
 contract L {
-    function f(mapping(uint=>uint) storage x, mapping(uint=>uint) storage y) internal {
-        // TODO: disallow assignment
-        x = y;
+    function f(mapping(uint=>uint) storage x, mapping(uint=>uint) storage y) internal { //~ WARN: function state mutability can be restricted to `pure`
+        x = y; //~ ERROR: types in storage containing (nested) mappings cannot be assigned to
     }
 }
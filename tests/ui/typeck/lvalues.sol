@@ -0,0 +1,65 @@
+contract C {
+    uint256 constant CONST = 1;
+    uint256 immutable imm;
+    uint256 immutable imm2 = 2;
+    uint256 immutable imm3;
+    uint256 x;
+    uint256[] arr;
+    bytes32 b32;
+
+    struct S {
+        uint256 a;
+    }
+
+    constructor() {
+        imm = 1;
+        (imm3, x) = (1, 2);
+    }
+
+    function f() internal returns (uint256, uint256) { //~ WARN: function state mutability can be restricted to `pure`
+        return (1, 2);
+    }
+
+    function g() internal returns (uint256, bool) { //~ WARN: function state mutability can be restricted to `pure`
+        return (1, true);
+    }
+
+    function lvalues(S memory s) internal {
+        CONST = 2; //~ ERROR: cannot assign to a constant variable
+        imm = 2; //~ ERROR: cannot write to immutable here
+        (x, imm2) = (1, 2); //~ ERROR: cannot write to immutable here
+        delete CONST; //~ ERROR: cannot assign to a constant variable
+        CONST++; //~ ERROR: cannot assign to a constant variable
+        f() = (1, 2); //~ ERROR: expression has to be an lvalue
+        1 = x; //~ ERROR: expression has to be an lvalue
+        x + 1 = 2; //~ ERROR: expression has to be an lvalue
+        arr.length = 0; //~ ERROR: member `length` is read-only and cannot be used to resize arrays
+        arr.length++; //~ ERROR: member `length` is read-only and cannot be used to resize arrays
+        b32[0] = 0x01; //~ ERROR: single bytes in fixed bytes arrays cannot be modified
+        msg.sender = address(0); //~ ERROR: expression has to be an lvalue
+        f = g; //~ ERROR: expression has to be an lvalue
+        delete (x, x); //~ ERROR: expression has to be an lvalue
+
+        arr.push() = 1;
+        arr[0] = 1;
+        s.a = 1;
+        delete s;
+        x++;
+    }
+
+    function destructuring() internal {
+        uint256 a;
+        uint256 b;
+        bool c;
+        (a, b) = f();
+        (a, ) = f();
+        (, b) = f();
+        (a, c) = g();
+        (a, b) = (b, a);
+        (a, b) = (1, 2, 3); //~ ERROR: different number of components on the left hand side (2) than on the right hand side (3)
+        (a, b, c) = f(); //~ ERROR: different number of components on the left hand side (3) than on the right hand side (2)
+        (a, b) = g(); //~ ERROR: type `bool` is not implicitly convertible to expected type `uint256`
+        (a, c) = (1, 2); //~ ERROR: type `int_const` is not implicitly convertible to expected type `bool`
+        (a, (b, c)) = (1, (2, 3)); //~ ERROR: type `int_const` is not implicitly convertible to expected type `bool`
+    }
+}
@@ -6,14 +6,14 @@ struct Nested {
     S s;
 }
 
-function free_1(S memory) {}
+function free_1(S memory) {} //~ ERROR: only valid in storage because it contains a (nested) mapping
 function free_2(S storage) {}
-function free_3() returns(S memory) {}
+function free_3() returns(S memory) {} //~ ERROR: only valid in storage because it contains a (nested) mapping
 function free_4() returns(S storage) {}
 
-function free_nested_1(Nested memory) {}
+function free_nested_1(Nested memory) {} //~ ERROR: only valid in storage because it contains a (nested) mapping
 function free_nested_2(Nested storage) {}
-function free_nested_3() returns(Nested memory) {}
+function free_nested_3() returns(Nested memory) {} //~ ERROR: only valid in storage because it contains a (nested) mapping
 function free_nested_4() returns(Nested storage) {}
 
 contract C {
@@ -36,12 +36,12 @@ contract C {
     function func_4() public returns(S storage) {} //~ ERROR: types containing mappings cannot be parameter or return types of public functions
     //~^ ERROR: invalid data location
 
-    modifier mod_1(S memory) { _; }
+    modifier mod_1(S memory) { _; } //~ ERROR: only valid in storage because it contains a (nested) mapping
     modifier mod_2(S storage) { _; }
 
-    function func_internal_1(S memory) internal {}
+    function func_internal_1(S memory) internal {} //~ ERROR: only valid in storage because it contains a (nested) mapping
     function func_internal_2(S storage) internal {}
-    function func_internal_3() internal returns(S memory) {}
+    function func_internal_3() internal returns(S memory) {} //~ ERROR: only valid in storage because it contains a (nested) mapping
     function func_internal_4() internal returns(S storage) {}
 
     function func_nested_1(Nested memory) public {}            //~ ERROR: types containing mappings cannot be parameter or return types of public functions
@@ -51,11 +51,11 @@ contract C {
     function func_nested_4() public returns(Nested storage) {} //~ ERROR: types containing mappings cannot be parameter or return types of public functions
     //~^ ERROR: invalid data location
 
-    modifier mod_nested_1(Nested memory) { _; }
+    modifier mod_nested_1(Nested memory) { _; } //~ ERROR: only valid in storage because it contains a (nested) mapping
     modifier mod_nested_2(Nested storage) { _; }
 
-    function func_internal_nested_1(Nested memory) internal {}
+    function func_internal_nested_1(Nested memory) internal {} //~ ERROR: only valid in storage because it contains a (nested) mapping
     function func_internal_nested_2(Nested storage) internal {}
-    function func_internal_nested_3() internal returns(Nested memory) {}
+    function func_internal_nested_3() internal returns(Nested memory) {} //~ ERROR: only valid in storage because it contains a (nested) mapping
     function func_internal_nested_4() internal returns(Nested storage) {}
 }
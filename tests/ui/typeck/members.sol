@@ -0,0 +1,64 @@
+import "./auxiliary/members_aux.sol" as Aux;
+
+type U is uint256;
+
+struct S {
+    uint256 a;
+    uint256[] arr;
+}
+
+contract C {
+    enum E { A, B }
+
+    S s;
+    uint256[] arr;
+    bytes b;
+
+    function f() external {}
+
+    function test(address a, address payable ap, bytes4 b4, S memory sm) public {
+        uint256 x = s.a;
+        uint256[] storage y = s.arr;
+        uint256 z = sm.a;
+        uint256 len = arr.length;
+        arr.push(1);
+        arr.push() = 2;
+        arr.pop();
+        b.push(0x01);
+        uint8 bl = b4.length;
+        uint256 bal = a.balance;
+        (bool ok, bytes memory data) = a.call("");
+        ap.transfer(1);
+        bool sent = ap.send(1);
+        address sender = msg.sender;
+        uint256 ts = block.timestamp;
+        E e = E.A;
+        U u = U.wrap(1);
+        uint256 v = U.unwrap(u);
+        uint8 max = type(uint8).max;
+        int16 min = type(int16).min;
+        E emax = type(E).max;
+        string memory name = type(C).name;
+        bytes4 id = type(I).interfaceId;
+        this.f();
+        uint256 fromAux = Aux.auxFn();
+        Aux.AuxStruct memory auxStruct;
+        x; y; z; len; bl; bal; ok; data; sent; sender; ts; e; v; max; min; emax; name; id; fromAux; auxStruct;
+
+        s.c; //~ ERROR: member `c` not found on type `struct S storage`
+        sm.aa; //~ ERROR: member `aa` not found on type `struct S memory`
+        a.transfer(1); //~ ERROR: member `transfer` not found on type `address`
+        a.balanse; //~ ERROR: member `balanse` not found on type `address`
+        E.C; //~ ERROR: member `C` not found on type `type(enum C.E)`
+        U.wrapp(1); //~ ERROR: member `wrapp` not found on type `type(U)`
+        sm.arr.push(1); //~ ERROR: member `push` not found on type `uint256[] memory`
+        msg.senderr; //~ ERROR: member `senderr` not found on type `msg`
+        type(bool).max; //~ ERROR: member `max` not found on type `type(bool)`
+        this.g(); //~ ERROR: member `g` not found on type `contract C`
+        Aux.nope; //~ ERROR: member `nope` not found on type `module
+        arr.push; //~ ERROR: unable to resolve overloaded member `push` without a call
+        arr.push(1, 2); //~ ERROR: no matching overload found for `push` with the given arguments
+    }
+}
+
+interface I {}
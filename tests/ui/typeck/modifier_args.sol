@@ -0,0 +1,54 @@
+contract A {
+    uint256 x;
+
+    constructor(uint256 a) {
+        x = a;
+    }
+
+    modifier onlyAbove(uint256 min) {
+        require(x > min);
+        _;
+    }
+
+    modifier noArgs() {
+        _;
+    }
+}
+
+contract B is A(1) {
+    function f(uint256 min) public view onlyAbove(min) noArgs {}
+
+    function g() public view onlyAbove(true) {} //~ ERROR: not implicitly convertible
+
+    function h() public view onlyAbove(1, 2) {} //~ ERROR: wrong argument count for modifier call: 2 arguments given but expected 1
+
+    function i() public view noArgs(1) {} //~ ERROR: wrong argument count for modifier call: 1 arguments given but expected 0
+}
+
+contract C is A {
+    constructor() A("a") {} //~ ERROR: type `literal_string` is not implicitly convertible to expected type `uint256`
+}
+
+contract D is A {
+    constructor(uint256 v) A(v) {}
+}
+
+contract E is B, D { //~ ERROR: base constructor arguments given twice
+    constructor() D(1) {}
+}
+
+contract F is A {} //~ ERROR: no arguments passed to the base constructor of `A`
+
+abstract contract G is A {}
+
+contract H is G {} //~ ERROR: no arguments passed to the base constructor of `A`
+
+contract I is A(1) {
+    constructor() A(2) {} //~ ERROR: base constructor arguments given twice
+}
+
+contract J is A(1, 2) {} //~ ERROR: wrong argument count for base constructor call: 2 arguments given but expected 1
+
+contract K {}
+
+contract L is K(1) {} //~ ERROR: wrong argument count for base constructor call: 1 arguments given but expected 0
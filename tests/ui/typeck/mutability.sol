@@ -0,0 +1,118 @@
+interface IERC20 {
+    function balanceOf(address) external view returns (uint256);
+    function transfer(address, uint256) external returns (bool);
+}
+
+contract C {
+    struct S {
+        uint256 x;
+    }
+
+    uint256 value;
+    uint256 constant CONSTANT = 1;
+    uint256 immutable LITERAL = 2;
+    uint256 immutable LITERAL_EXPR = 1 + 2;
+    bool immutable BOOL_LITERAL = true;
+    uint256 immutable COMPUTED = block.number;
+    uint256[] array;
+    mapping(uint256 => uint256) map;
+    S s;
+    IERC20 token;
+
+    event E();
+
+    modifier reads() {
+        require(value > 0);
+        _;
+    }
+
+    modifier usesValue() {
+        require(msg.value > 0);
+        _;
+    }
+
+    function pureOk(uint256 a) public pure returns (uint256) {
+        bytes32 h = keccak256(msg.data);
+        return a + CONSTANT + LITERAL + LITERAL_EXPR + uint256(h);
+    }
+
+    function pureReads() public pure returns (uint256) {
+        uint256 a = value; //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        a += block.timestamp; //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        a += COMPUTED; //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        if (BOOL_LITERAL) {} //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        a += address(this).balance; //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        //~^ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        a += map[1]; //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        //~^ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        a += viewFn(); //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+        return a;
+    }
+
+    function viewFn() public view returns (uint256) {
+        S storage p = s;
+        return value + p.x + array.length + token.balanceOf(msg.sender);
+    }
+
+    function viewWrites() public view {
+        value = 1; //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        value++; //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        delete array; //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        map[1] = 2; //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        s.x = 3; //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        array.push(4); //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        token.transfer(msg.sender, 1); //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        emit E(); //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+        payable(msg.sender).transfer(1); //~ ERROR: function declared as `view`, but this expression (potentially) modifies the state and thus requires non-payable (the default) or payable
+    }
+
+    function viewLocals() public view returns (uint256) {
+        S memory m;
+        m.x = 1;
+        (uint256 a, uint256 b) = (value, 2);
+        a = b;
+        return a + m.x;
+    }
+
+    function pureModifier() public pure reads {} //~ ERROR: function declared as `pure`, but this expression (potentially) reads from the environment or state and thus requires `view`
+
+    function nonPayableValue() public view returns (uint256) {
+        return msg.value; //~ ERROR: `msg.value` can only be used in payable public functions
+    }
+
+    function internalValue() internal view returns (uint256) {
+        return msg.value;
+    }
+
+    function payableValue() public payable returns (uint256) {
+        return msg.value;
+    }
+
+    function nonPayableModifier() public usesValue {} //~ ERROR: this modifier uses `msg.value` and thus the function has to be payable or internal
+
+    function canBePure(uint256 a) public returns (uint256) { //~ WARN: function state mutability can be restricted to `pure`
+        return a * 2;
+    }
+
+    function canBeView() external returns (uint256) { //~ WARN: function state mutability can be restricted to `view`
+        return value;
+    }
+
+    function viewCanBePure() internal view returns (uint256) { //~ WARN: function state mutability can be restricted to `pure`
+        return CONSTANT;
+    }
+
+    function virtualFn() public virtual returns (uint256) {
+        return 1;
+    }
+
+    function emptyFn() public {}
+
+    function payableFn() public payable returns (uint256) {
+        return 1;
+    }
+
+    function writes() public {
+        value = 1;
+    }
+}
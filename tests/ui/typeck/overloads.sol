@@ -0,0 +1,44 @@
+contract C {
+    event Ev(uint256 a);
+    event Ev(bool b);
+
+    function f(uint256 a) internal pure returns (uint256) {} //~ WARN: unnamed return variable can remain unassigned
+    function f(bool b) internal pure returns (bool) {} //~ WARN: unnamed return variable can remain unassigned
+    function f(uint256 a, uint256 b) internal pure {}
+
+    function g(uint8 a) internal pure {}
+    function g(uint256 a) internal pure {}
+
+    function h(address a) internal pure {}
+    function h(bytes4 b) internal pure {}
+
+    function n(uint256 a, bool b) internal pure {}
+    function n(bool b, uint256 c) internal pure {}
+
+    function test(uint8 u8, uint256 u256) public {
+        uint256 a = f(1);
+        bool b = f(true);
+        f(1, 2);
+        f({a: 1, b: 2});
+        f({b: true});
+        g(u256);
+        h(address(0));
+        h(0x12345678);
+        n({a: 1, b: true});
+        n({b: true, c: 1});
+        emit Ev(1);
+        emit Ev(true);
+        a; b;
+
+        f("a"); //~ ERROR: no matching overload found for `f` with the given arguments
+        f(1, 2, 3); //~ ERROR: no matching overload found for `f` with the given arguments
+        f({c: 1}); //~ ERROR: no matching overload found for `f` with the given arguments
+        g(u8); //~ ERROR: ambiguous call to overloaded `g`: multiple declarations match
+        g(1); //~ ERROR: ambiguous call to overloaded `g`: multiple declarations match
+        n({b: true}); //~ ERROR: no matching overload found for `n` with the given arguments
+        emit Ev(""); //~ ERROR: no matching overload found for `Ev` with the given arguments
+        uint256 c = f(true); //~ ERROR: type `bool` is not implicitly convertible to expected type `uint256`
+        c;
+        f; //~ ERROR: unable to resolve overloaded identifier `f` without a call
+    }
+}
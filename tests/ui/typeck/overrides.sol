@@ -0,0 +1,105 @@
+interface IERC20 {
+    function totalSupply() external view returns (uint256);
+    function balanceOf(address) external view returns (uint256);
+}
+
+abstract contract Initializable {
+    function _init() internal virtual;
+
+    function version() public pure virtual returns (uint256) {
+        return 1;
+    }
+}
+
+contract Token is IERC20, Initializable {
+    uint256 public override totalSupply;
+
+    function balanceOf(address) external pure returns (uint256) {
+        return 0;
+    }
+
+    function _init() internal override {}
+
+    function version() public pure override returns (uint256) {
+        return 2;
+    }
+}
+
+contract Base {
+    function a() public virtual {}
+    function b() public {}
+    function c() external view virtual returns (uint256) {} //~ WARN: unnamed return variable can remain unassigned
+    function d() public view virtual {}
+    function e() public virtual returns (uint256) {} //~ WARN: unnamed return variable can remain unassigned
+    function f() internal virtual {}
+    function g() public virtual {}
+    function i() public payable virtual {}
+    function k() public view virtual returns (uint256) {} //~ WARN: unnamed return variable can remain unassigned
+
+    modifier m() virtual { _; }
+    modifier n() { _; }
+}
+
+contract Derived is Base {
+    function a() public {} //~ ERROR: overriding function is missing `override` specifier
+    function b() public override {} //~ ERROR: trying to override non-virtual function
+    function c() public view override returns (uint256) {} //~ WARN: unnamed return variable can remain unassigned
+    function d() public override {} //~ ERROR: overriding function changes state mutability from `view` to `nonpayable`
+    function e() public override returns (bool) {} //~ ERROR: overriding function return types differ
+    //~^ WARN: unnamed return variable can remain unassigned
+    function f() public override {} //~ ERROR: overriding function visibility differs
+    function g(uint256) public override {} //~ ERROR: function has override specified but does not override anything
+    function i() public override {} //~ ERROR: overriding function changes state mutability from `payable` to `nonpayable`
+
+    modifier m() override { _; }
+    modifier n() override { _; } //~ ERROR: trying to override non-virtual modifier
+}
+
+contract X {
+    function f() public virtual {}
+    function g() public virtual {}
+}
+
+contract Y {
+    function f() public virtual {}
+    function g() public virtual {}
+}
+
+contract Z is X, Y { //~ ERROR: derived contract must override function `g`
+    function f() public override(X, Y) {}
+}
+
+contract W is X, Y { //~ ERROR: derived contract must override function `g`
+    function f() public override {} //~ ERROR: function needs to specify overridden contracts `X` and `Y`
+}
+
+contract Empty {}
+
+contract V is X, Y, Empty { //~ ERROR: derived contract must override function `g`
+    function f() public override(X, Empty) {} //~ ERROR: invalid contract specified in override list: `Empty`
+    //~^ ERROR: function needs to specify overridden contract `Y`
+}
+
+contract Diamond is X {
+    function g() public override {}
+}
+
+contract Diamond2 is X, Diamond {} //~ ERROR: derived contract must override function `g`
+
+contract VarOverride is Base {
+    uint256 public override c;
+    uint256 internal override q; //~ ERROR: `override` can only be used with public state variables
+    uint256 public override k; //~ ERROR: public state variables can only override functions with external visibility
+}
+
+abstract contract Unimplemented is Base {
+    function a() public virtual override; //~ ERROR: overriding an implemented function with an unimplemented function is not allowed
+}
+
+contract Virtuals {
+    function p() private virtual {} //~ ERROR: `virtual` and `private` cannot be used together
+}
+
+library Lib {
+    function f() internal virtual {} //~ ERROR: library functions cannot be `virtual`
+}
@@ -0,0 +1,49 @@
+contract A {
+    function f() public virtual returns (uint256 r) {
+        r = 1;
+    }
+
+    function g(uint256 x) internal pure virtual returns (uint256) {
+        return x;
+    }
+
+    function h() external virtual {}
+
+    function p() private {}
+
+    function v() public view virtual returns (uint256) {
+        return 1;
+    }
+}
+
+abstract contract B is A {
+    function f() public virtual override returns (uint256 r) {
+        r = super.f() + 1;
+    }
+
+    function g(uint256 x) internal pure virtual override returns (uint256) {
+        return super.g(x) * 2;
+    }
+
+    function u() public virtual;
+}
+
+contract C is B {
+    function f() public override returns (uint256) {
+        uint256 a = super.f();
+        uint256 b = super.g(a);
+        bool c = super.g(a); //~ ERROR: type `uint256` is not implicitly convertible to expected type `bool`
+        super.h(); //~ ERROR: member `h` not found on type `contract super C`
+        super.p(); //~ ERROR: member `p` not found on type `contract super C`
+        super.u(); //~ ERROR: member `u` not found on type `contract super C`
+        super.missing(); //~ ERROR: member `missing` not found on type `contract super C`
+        c;
+        return a + b;
+    }
+
+    function u() public override {}
+
+    function v() public view override returns (uint256) {
+        return super.v() + 1;
+    }
+}
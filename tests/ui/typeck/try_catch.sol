@@ -0,0 +1,72 @@
+interface I {
+    function get() external returns (uint256, string memory);
+    function none() external;
+}
+
+contract D {
+    constructor(uint256) payable {}
+}
+
+contract C {
+    I i;
+    function() external returns (uint256) fp;
+
+    function pub() public returns (bool ok) {}
+
+    function internalFn() internal returns (uint256 x) {}
+
+    function ext() external {}
+
+    function calls() public {
+        try i.get() returns (uint256 a, string memory s) {
+            a;
+            s;
+        } catch {}
+        try i.get() {} catch {}
+        try i.none() {} catch (bytes memory reason) {
+            reason;
+        }
+        try this.pub() returns (bool ok) {
+            ok;
+        } catch Error(string memory reason) {
+            reason;
+        } catch Panic(uint256 code) {
+            code;
+        } catch (bytes memory data) {
+            data;
+        }
+        try fp() returns (uint256) {} catch {}
+        try new D{value: 1}(1) returns (D d) {
+            d;
+        } catch {}
+        try new D(1) {} catch {}
+    }
+
+    function invalidCalls() public {
+        try internalFn() {} catch {} //~ ERROR: try can only be used with external function calls and contract creation calls
+        try pub() {} catch {} //~ ERROR: try can only be used with external function calls and contract creation calls
+        try ext() {} catch {} //~ ERROR: try can only be used with external function calls and contract creation calls
+        try new uint256[](1) {} catch {} //~ ERROR: try can only be used with external function calls and contract creation calls
+        try address(this).call("") {} catch {} //~ ERROR: try can only be used with external function calls and contract creation calls
+        try i {} catch {} //~ ERROR: try can only be used with external function calls and contract creation calls
+    }
+
+    function invalidReturns() public {
+        try i.get() returns (uint256) {} catch {} //~ ERROR: function returns 2 values, but the `returns` clause declares 1 variables
+        try i.none() returns (uint256) {} catch {} //~ ERROR: function returns 0 values, but the `returns` clause declares 1 variables
+        try i.get() returns (uint128, string memory) {} catch {} //~ ERROR: invalid type for return variable: expected `uint256`, found `uint128`
+        try i.get() returns (uint256, bytes memory) {} catch {} //~ ERROR: invalid type for return variable
+        try new D(1) returns (C) {} catch {} //~ ERROR: invalid type for return variable: expected `contract D`, found `contract C`
+    }
+
+    function invalidCatches() public {
+        try i.none() {} catch Error(bytes memory) {} //~ ERROR: expected `catch Error(string memory ...) { ... }`
+        try i.none() {} catch Panic(uint8) {} //~ ERROR: expected `catch Panic(uint256 ...) { ... }`
+        try i.none() {} catch (string memory) {} //~ ERROR: expected `catch (bytes memory ...) { ... }` or `catch { ... }`
+        try i.none() {} catch (bytes memory, uint256) {} //~ ERROR: expected `catch (bytes memory ...) { ... }` or `catch { ... }`
+        try i.none() {} catch Custom(uint256) {} //~ ERROR: invalid catch clause name
+        try i.none() {} catch Error(string memory) {} catch Error(string memory) {} //~ ERROR: this try statement already has an `Error` catch clause
+        try i.none() {} catch Panic(uint256) {} catch Panic(uint256) {} //~ ERROR: this try statement already has a `Panic` catch clause
+        try i.none() {} catch {} catch (bytes memory) {} //~ ERROR: this try statement already has a low-level catch clause
+    }
+}
@@ -0,0 +1,75 @@
+contract C {
+    struct S {
+        uint256 a;
+    }
+
+    S s;
+    mapping(uint256 => uint256) map;
+
+    function declarations() internal view {
+        S storage p; //~ ERROR: uninitialized storage pointer
+        mapping(uint256 => uint256) storage m; //~ ERROR: uninitialized mapping
+        S storage q = s;
+        S memory mem;
+        p; m; q; mem;
+    }
+
+    function storageReturn(bool c) internal view returns (S storage r) { //~ ERROR: this variable is of storage pointer type and can be returned without prior assignment
+        if (c) {
+            r = s;
+        }
+    }
+
+    function storageReturnAssigned(bool c) internal view returns (S storage r) {
+        if (c) {
+            r = s;
+        } else {
+            return s;
+        }
+    }
+
+    function unnamedStorageReturn() internal view returns (S storage) {} //~ ERROR: this variable is of storage pointer type and can be returned without prior assignment
+
+    function readReturn() internal view returns (S storage r) {
+        r.a; //~ ERROR: this variable is of storage pointer type and can be accessed without prior assignment
+        r = s;
+    }
+
+    function revertingReturn() internal pure returns (S storage r) { //~ WARN: unused return variable
+        revert();
+    }
+
+    function calldataLocal(uint256[] calldata a, bool c) external pure returns (uint256) {
+        uint256[] calldata x;
+        if (c) {
+            x = a;
+        }
+        return x.length; //~ ERROR: this variable is of calldata pointer type and can be accessed without prior assignment
+    }
+
+    function calldataLocalAssigned(uint256[] calldata a) external pure returns (uint256) {
+        uint256[] calldata x;
+        x = a;
+        uint256[] calldata y;
+        (y, x) = (x, a);
+        return x.length + y.length;
+    }
+
+    // Non-pointer locals are zero-initialized, so reading them before an assignment is allowed.
+    function zeroInitializedLocals(bool c) internal pure returns (uint256) {
+        S memory m;
+        uint256[] memory arr;
+        uint256 sum;
+        if (c) {
+            sum = 1;
+        }
+        sum += m.a + arr.length;
+        return sum;
+    }
+
+    function calldataSelfAssign(uint256[] calldata a) external pure {
+        uint256[] calldata x;
+        x = x; //~ ERROR: this variable is of calldata pointer type and can be accessed without prior assignment
+        x = a;
+    }
+}
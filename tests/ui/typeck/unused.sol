@@ -0,0 +1,89 @@
+contract C {
+    uint256 private unusedPrivate; //~ WARN: unused state variable `unusedPrivate`
+    uint256 private usedPrivate;
+    uint256 internal unusedInternal;
+    uint256 public publicVar;
+    uint256 private constant UNUSED_CONST = 1; //~ WARN: unused state variable `UNUSED_CONST`
+    uint256 private constant USED_CONST = 2;
+
+    function f(uint256 a, uint256 b, uint256) public returns (uint256 r) { //~ WARN: unused function parameter
+    //~^ WARN: unused return variable
+        uint256 x = a; //~ WARN: unused local variable
+        uint256 y;
+        y = 1;
+        usedPrivate = USED_CONST;
+    }
+
+    function g(uint256 a) internal pure returns (uint256 documented) {
+        return a;
+    }
+
+    function h(uint256 a) internal pure returns (uint256 r) { //~ WARN: unused return variable
+        a;
+    }
+
+    function empty(uint256 a) internal virtual {}
+
+    function tryCatch(C c) external { //~ WARN: function state mutability can be restricted to `pure`
+        try c.g2() returns (uint256 v) { //~ WARN: unused try/catch parameter
+        } catch Error(string memory reason) { //~ WARN: unused try/catch parameter
+            revert();
+        } catch (bytes memory) {
+        }
+    }
+
+    function g2() external pure returns (uint256) {
+        return 1;
+    }
+
+    modifier m(uint256 a) { //~ WARN: unused function parameter
+        _;
+    }
+}
+
+// Internal variables are not reported, even without a derived contract in this compilation, since
+// they can be used by derived contracts that are compiled separately.
+contract Standalone {
+    uint256 internal forDownstream;
+}
+
+abstract contract Base {
+    uint256 internal forDerived;
+    uint256 private unusedBase; //~ WARN: unused state variable `unusedBase`
+}
+
+contract Derived is Base {
+    function f() public view returns (uint256) {
+        return forDerived;
+    }
+}
+
+contract Assembly {
+    uint256 private assemblyVar;
+
+    function withAssembly(uint256 a) internal view returns (uint256 r) {
+        assembly {
+            r := add(a, sload(assemblyVar.slot))
+        }
+    }
+}
+
+contract ArrayLengths {
+    uint256 private constant STATE_LEN = 2;
+    uint256 private constant PARAM_LEN = 3;
+    uint256 private constant RETURN_LEN = 4;
+    uint256 private constant FIELD_LEN = 5;
+    uint256 private constant EVENT_LEN = 6;
+
+    struct S {
+        uint256[FIELD_LEN] field;
+    }
+
+    event Ev(uint256[EVENT_LEN] values);
+
+    uint256[STATE_LEN] public values;
+
+    function lengths(uint256[PARAM_LEN] memory a) public pure returns (uint256[RETURN_LEN] memory b) {
+        b[0] = a[0];
+    }
+}
@@ -0,0 +1,127 @@
+library SafeMath {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a - b;
+    }
+
+    function hidden(uint256 a) private pure returns (uint256) {
+        return a;
+    }
+}
+
+struct Counter {
+    uint256 value;
+}
+
+library Counters {
+    function increment(Counter storage counter) internal {
+        counter.value += 1;
+    }
+
+    function current(Counter memory counter) internal pure returns (uint256) {
+        return counter.value;
+    }
+}
+
+function double(uint256 x) pure returns (uint256) {
+    return x * 2;
+}
+
+function scale(uint256 x, uint256 factor) pure returns (uint256) {
+    return x * factor;
+}
+
+function noParams() pure {}
+
+using {double, scale} for uint256;
+
+type Fixed is int256;
+
+function add(Fixed a, Fixed b) pure returns (Fixed) {
+    return Fixed.wrap(Fixed.unwrap(a) + Fixed.unwrap(b));
+}
+
+function neg(Fixed a) pure returns (Fixed) {
+    return Fixed.wrap(-Fixed.unwrap(a));
+}
+
+function sub(Fixed a, Fixed b) pure returns (Fixed) {
+    return Fixed.wrap(Fixed.unwrap(a) - Fixed.unwrap(b));
+}
+
+function eq(Fixed a, Fixed b) pure returns (bool) {
+    return Fixed.unwrap(a) == Fixed.unwrap(b);
+}
+
+function badEq(Fixed a, Fixed) pure returns (Fixed) {
+    return a;
+}
+
+function badMul(Fixed a) pure returns (Fixed) {
+    return a;
+}
+
+function noReturn(Fixed, Fixed) pure {}
+
+function viewOp(Fixed a, Fixed) view returns (Fixed) {
+    return block.timestamp > 0 ? a : a;
+}
+
+using {add as +, neg as -, sub as -, eq as ==} for Fixed global;
+using {badEq as !=, badMul as *} for Fixed global;
+//~^ ERROR: wrong return parameters in operator definition
+//~| ERROR: wrong parameters in operator definition
+using {noReturn as %} for Fixed global; //~ ERROR: wrong return parameters in operator definition
+using {viewOp as /} for Fixed global; //~ ERROR: only pure free functions can be used to define operators
+using {add as +} for uint256 global;
+//~^ ERROR: can only use `global` with user-defined types
+//~| ERROR: user-defined operators can only be implemented for user-defined value types
+using {noParams} for uint256; //~ ERROR: the function `noParams` does not have any parameters, and therefore cannot be attached to the type `uint256`
+using {double} for bool; //~ ERROR: the function `double` cannot be attached to the type `bool`
+
+contract Base {
+    using SafeMath for uint256;
+}
+
+contract C is Base {
+    using SafeMath for uint256;
+    using Counters for Counter;
+    using {neg as -} for Fixed; //~ ERROR: user-defined operators can only be defined in a global `using for` directive
+
+    Counter counter;
+
+    function attached(uint256 x) public pure returns (uint256) {
+        uint256 y = x.add(1).sub(2);
+        y = y.double().scale(3);
+        y = y.scale({factor: 4});
+        return y.hidden(); //~ ERROR: member `hidden` not found on type `uint256`
+    }
+
+    function storagePointers() public {
+        counter.increment();
+        Counter memory copy = counter;
+        copy.current();
+        copy.increment(); //~ ERROR: member `increment` not found on type `struct Counter memory`
+    }
+
+    function operators(Fixed a, Fixed b) public pure returns (Fixed, bool) {
+        Fixed c = a + b;
+        c = -c;
+        c = c - a;
+        bool same = a == b;
+        c = a * b; //~ ERROR: cannot apply binary operator `*` to types `Fixed` and `Fixed`
+        c = a + 1; //~ ERROR: type `int_const` is not implicitly convertible to expected type `Fixed`
+        a % b; //~ ERROR: user-defined operator must return exactly one value
+        c += a; //~ ERROR: cannot apply binary operator `+` to types `Fixed` and `Fixed`
+        return (c, same);
+    }
+}
+
+contract NotInherited is Base {
+    function f(uint256 x) public pure returns (uint256) {
+        return x.add(1); //~ ERROR: member `add` not found on type `uint256`
+    }
+}